@@ -0,0 +1,203 @@
+use std::{collections::BTreeMap, fs, path::Path};
+
+use toml::Value;
+
+use crate::{
+    error::AocError,
+    input::is_profile_name,
+    solution::{Answer, Part},
+};
+
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// Accepted answers, stored as `[YYYY.dayN]` tables with `part1` and `part2`
+/// keys, and as `[profile.YYYY.dayN]` tables for the inputs of a profile.
+#[derive(Debug, Default, PartialEq)]
+pub struct Answers {
+    answers: BTreeMap<(Option<String>, u16, u8, Part), Answer>,
+}
+
+/// The `dayN` tables of a year.
+type Days = BTreeMap<String, BTreeMap<String, Value>>;
+
+fn parse_year(key: &str) -> Result<u16, AocError> {
+    return key
+        .parse::<u16>()
+        .map_err(|_| AocError::parse(format!("invalid year {:?} in the answers", key)));
+}
+
+fn parse_day(key: &str) -> Result<u8, AocError> {
+    return key
+        .strip_prefix("day")
+        .and_then(|day| day.parse::<u8>().ok())
+        .ok_or_else(|| AocError::parse(format!("invalid day {:?} in the answers", key)));
+}
+
+fn parse_part(key: &str) -> Result<Part, AocError> {
+    return key
+        .strip_prefix("part")
+        .and_then(|part| part.parse::<u8>().ok())
+        .and_then(Part::from_number)
+        .ok_or_else(|| AocError::parse(format!("invalid part {:?} in the answers", key)));
+}
+
+fn parse_answer(value: &Value) -> Result<Answer, AocError> {
+    return match value {
+        Value::Integer(number) => Ok(Answer::from(*number)),
+        Value::String(text) => Ok(Answer::from(text.as_str())),
+        other => Err(AocError::parse(format!("invalid answer {}", other))),
+    };
+}
+
+impl Answers {
+    pub fn parse(content: &str) -> Result<Answers, AocError> {
+        let table: BTreeMap<String, Value> =
+            toml::from_str(content).map_err(|e| AocError::parse(e.to_string()))?;
+
+        let mut answers = Answers::default();
+        for (key, value) in table {
+            let invalid = |e: toml::de::Error| AocError::parse(format!("{}: {}", key, e));
+            if is_profile_name(&key) {
+                let years: BTreeMap<String, Days> = value.try_into().map_err(invalid)?;
+                for (year_key, days) in &years {
+                    answers.insert_days(Some(&key), parse_year(year_key)?, days)?;
+                }
+            } else {
+                let days: Days = value.try_into().map_err(invalid)?;
+                answers.insert_days(None, parse_year(&key)?, &days)?;
+            }
+        }
+        return Ok(answers);
+    }
+
+    fn insert_days(
+        &mut self,
+        profile: Option<&str>,
+        year: u16,
+        days: &Days,
+    ) -> Result<(), AocError> {
+        for (day_key, parts) in days {
+            let day = parse_day(day_key)?;
+            for (part_key, value) in parts {
+                self.answers.insert(
+                    (
+                        profile.map(str::to_string),
+                        year,
+                        day,
+                        parse_part(part_key)?,
+                    ),
+                    parse_answer(value)?,
+                );
+            }
+        }
+        return Ok(());
+    }
+
+    /// A missing file is an empty set of answers.
+    pub fn load(path: &Path) -> Result<Answers, AocError> {
+        if !path.exists() {
+            return Ok(Answers::default());
+        }
+        let content = fs::read_to_string(path)
+            .map_err(|e| AocError::Io(format!("unable to read {}: {}", path.display(), e)))?;
+        return Answers::parse(&content);
+    }
+
+    /// `profile` is `None` for the inputs of the account the tools run for.
+    pub fn get(&self, profile: Option<&str>, year: u16, day: u8, part: Part) -> Option<&Answer> {
+        return self
+            .answers
+            .get(&(profile.map(str::to_string), year, day, part));
+    }
+}
+
+/// The accepted answer of a part, for the tests running on the real inputs.
+#[cfg(test)]
+pub fn known_answer(year: u16, day: u8, part: Part) -> Answer {
+    let answers = Answers::load(Path::new(ANSWERS_FILE)).expect("Unable to load the answers");
+    return answers
+        .get(None, year, day, part)
+        .cloned()
+        .unwrap_or_else(|| panic!("No known answer for day {} part {} of {}", day, part, year));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_numbers_and_text() {
+        let answers = Answers::parse(
+            "[2022.day1]
+part1 = 67633
+
+[2022.day5]
+part2 = \"GGNPJBTTR\"
+
+[2021.day1]
+part2 = 1",
+        )
+        .unwrap();
+        assert_eq!(
+            answers.get(None, 2022, 1, Part::One),
+            Some(&Answer::Number(67633))
+        );
+        assert_eq!(
+            answers.get(None, 2022, 5, Part::Two),
+            Some(&Answer::from("GGNPJBTTR"))
+        );
+        assert_eq!(answers.get(None, 2022, 1, Part::Two), None);
+        assert_eq!(
+            answers.get(None, 2021, 1, Part::Two),
+            Some(&Answer::Number(1))
+        );
+    }
+
+    #[test]
+    fn should_keep_the_answers_of_each_profile() {
+        let answers = Answers::parse(
+            "[2022.day7]
+part1 = 1
+
+[alice.2022.day7]
+part1 = 2
+
+[bob.2021.day7]
+part2 = \"ABC\"",
+        )
+        .unwrap();
+        assert_eq!(
+            answers.get(None, 2022, 7, Part::One),
+            Some(&Answer::Number(1))
+        );
+        assert_eq!(
+            answers.get(Some("alice"), 2022, 7, Part::One),
+            Some(&Answer::Number(2))
+        );
+        assert_eq!(
+            answers.get(Some("bob"), 2021, 7, Part::Two),
+            Some(&Answer::from("ABC"))
+        );
+        assert_eq!(answers.get(Some("bob"), 2022, 7, Part::One), None);
+    }
+
+    #[test]
+    fn should_reject_unknown_keys() {
+        assert!(Answers::parse("[2022.dayone]\npart1 = 1").is_err());
+        assert!(Answers::parse("[2022.day1]\npart3 = 1").is_err());
+        assert!(Answers::parse("[2022.day1]\npart1 = 1.5").is_err());
+        assert!(Answers::parse("[y2022.day1]\npart1 = 1").is_err());
+        assert!(Answers::parse("[day1]\npart1 = 1").is_err());
+        assert!(Answers::parse("[alice.day1]\npart1 = 1").is_err());
+        assert!(Answers::parse("[alice.2022]\nday1 = 1").is_err());
+    }
+
+    #[test]
+    fn should_load_the_answers_file() {
+        let answers = Answers::load(Path::new(ANSWERS_FILE)).unwrap();
+        assert_eq!(
+            answers.get(None, 2022, 7, Part::One),
+            Some(&Answer::Number(1182909))
+        );
+    }
+}
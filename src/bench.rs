@@ -0,0 +1,453 @@
+use std::{collections::BTreeMap, fmt, fs, path::Path, sync::Arc, time::Duration};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::AocError,
+    runner::{self, Parsed},
+    solution::{Part, Puzzle},
+    table,
+};
+
+pub const DEFAULT_ITERATIONS: u32 = 100;
+
+/// Allowed slowdown of the median, in percent, before a run is a regression.
+pub const DEFAULT_THRESHOLD: f64 = 10.0;
+
+/// What is being timed: the parse step of a day or one of its parts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Phase {
+    Parse,
+    Solve(Part),
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "parse"),
+            Phase::Solve(part) => write!(f, "part{}", part),
+        }
+    }
+}
+
+impl Phase {
+    fn from_name(name: &str) -> Option<Phase> {
+        return match name {
+            "parse" => Some(Phase::Parse),
+            _ => name
+                .strip_prefix("part")
+                .and_then(|part| part.parse::<u8>().ok())
+                .and_then(Part::from_number)
+                .map(Phase::Solve),
+        };
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+    pub mean: Duration,
+}
+
+/// Nearest rank percentile of sorted samples.
+fn percentile(sorted: &[Duration], percent: usize) -> Duration {
+    let rank = (sorted.len() * percent).div_ceil(100).max(1);
+    return sorted[rank - 1];
+}
+
+impl Stats {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Option<Stats> {
+        if samples.is_empty() {
+            return None;
+        }
+        samples.sort();
+        let total: Duration = samples.iter().sum();
+        return Some(Stats {
+            min: samples[0],
+            median: percentile(&samples, 50),
+            p95: percentile(&samples, 95),
+            mean: total / samples.len() as u32,
+        });
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Measurement {
+    pub year: u16,
+    pub day: u8,
+    pub phase: Phase,
+    pub stats: Stats,
+}
+
+/// Solves `part` on the thread of [`runner::guarded`], handing the parsed
+/// input back with the answer and the time it took.
+fn solve(
+    puzzle: &'static dyn Puzzle,
+    part: Part,
+    parsed: Parsed,
+    timeout: Duration,
+) -> Result<(Result<(), AocError>, Parsed, Duration), AocError> {
+    let step = runner::guarded(timeout, move || {
+        let answer = puzzle.solve(part, parsed.as_ref()).map(|_| ());
+        return (answer, parsed);
+    })?;
+    let (answer, parsed) = step.value;
+    return Ok((answer, parsed, step.time));
+}
+
+/// Times the parse step and every solved part of a day, each run being
+/// guarded by `timeout` like in [`runner::run_puzzle`]. Unsolved parts and
+/// parts that panic are left out, any other error or a timeout stops the day.
+/// The parsed input is lost with a panicking part, and parsed again for the
+/// next one.
+pub fn bench_puzzle(
+    puzzle: &'static dyn Puzzle,
+    input: &str,
+    iterations: u32,
+    timeout: Duration,
+) -> Result<Vec<Measurement>, AocError> {
+    let (year, day) = (puzzle.year(), puzzle.day());
+    let input = Arc::new(input.to_string());
+    let mut parsed = Some(runner::parse(puzzle, &input, timeout)?.value);
+    let mut measurements = Vec::new();
+
+    let mut samples = Vec::new();
+    for _ in 0..iterations {
+        samples.push(runner::parse(puzzle, &input, timeout)?.time);
+    }
+    if let Some(stats) = Stats::from_samples(samples) {
+        measurements.push(Measurement {
+            year,
+            day,
+            phase: Phase::Parse,
+            stats,
+        });
+    }
+
+    for part in Part::ALL {
+        let owned = match parsed.take() {
+            Some(owned) => owned,
+            None => runner::parse(puzzle, &input, timeout)?.value,
+        };
+        let (answer, mut owned) = match solve(puzzle, part, owned, timeout) {
+            Ok((answer, owned, _)) => (answer, owned),
+            Err(AocError::Panicked(_)) => continue,
+            Err(error) => return Err(error),
+        };
+        match answer {
+            Ok(_) => {}
+            Err(AocError::NotImplemented { .. }) => {
+                parsed = Some(owned);
+                continue;
+            }
+            Err(error) => return Err(error),
+        }
+        let mut samples = Vec::new();
+        for _ in 0..iterations {
+            let (_, solved_with, time) = solve(puzzle, part, owned, timeout)?;
+            owned = solved_with;
+            samples.push(time);
+        }
+        parsed = Some(owned);
+        if let Some(stats) = Stats::from_samples(samples) {
+            measurements.push(Measurement {
+                year,
+                day,
+                phase: Phase::Solve(part),
+                stats,
+            });
+        }
+    }
+    return Ok(measurements);
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct BaselineEntry {
+    year: u16,
+    day: u8,
+    phase: String,
+    min_ns: u64,
+    median_ns: u64,
+    p95_ns: u64,
+    mean_ns: u64,
+}
+
+/// Medians of a previous run, saved as JSON to compare later runs against.
+#[derive(Debug, Default, PartialEq)]
+pub struct Baseline {
+    medians: BTreeMap<(u16, u8, Phase), Duration>,
+}
+
+fn nanos(duration: Duration) -> u64 {
+    return duration.as_nanos() as u64;
+}
+
+impl Baseline {
+    pub fn to_json(measurements: &[Measurement]) -> String {
+        let entries: Vec<BaselineEntry> = measurements
+            .iter()
+            .map(|measurement| BaselineEntry {
+                year: measurement.year,
+                day: measurement.day,
+                phase: measurement.phase.to_string(),
+                min_ns: nanos(measurement.stats.min),
+                median_ns: nanos(measurement.stats.median),
+                p95_ns: nanos(measurement.stats.p95),
+                mean_ns: nanos(measurement.stats.mean),
+            })
+            .collect();
+        return serde_json::to_string_pretty(&entries).expect("Unable to serialize the baseline");
+    }
+
+    pub fn from_json(content: &str) -> Result<Baseline, AocError> {
+        let entries: Vec<BaselineEntry> =
+            serde_json::from_str(content).map_err(|e| AocError::parse(e.to_string()))?;
+
+        let mut medians = BTreeMap::new();
+        for entry in entries {
+            let phase = Phase::from_name(&entry.phase).ok_or_else(|| {
+                AocError::parse(format!("invalid phase {:?} in the baseline", entry.phase))
+            })?;
+            medians.insert(
+                (entry.year, entry.day, phase),
+                Duration::from_nanos(entry.median_ns),
+            );
+        }
+        return Ok(Baseline { medians });
+    }
+
+    pub fn save(path: &Path, measurements: &[Measurement]) -> Result<(), AocError> {
+        return fs::write(path, Baseline::to_json(measurements))
+            .map_err(|e| AocError::Io(format!("unable to write {}: {}", path.display(), e)));
+    }
+
+    pub fn load(path: &Path) -> Result<Baseline, AocError> {
+        let content = fs::read_to_string(path)
+            .map_err(|e| AocError::Io(format!("unable to read {}: {}", path.display(), e)))?;
+        return Baseline::from_json(&content);
+    }
+
+    pub fn median(&self, year: u16, day: u8, phase: Phase) -> Option<Duration> {
+        return self.medians.get(&(year, day, phase)).copied();
+    }
+}
+
+/// Change of the median against the baseline, `0.25` being 25% slower.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Comparison {
+    pub baseline: Duration,
+    pub change: f64,
+    pub regression: bool,
+}
+
+pub fn compare(
+    measurement: &Measurement,
+    baseline: &Baseline,
+    threshold: f64,
+) -> Option<Comparison> {
+    let previous = baseline.median(measurement.year, measurement.day, measurement.phase)?;
+    let change = if previous.is_zero() {
+        0.0
+    } else {
+        measurement.stats.median.as_secs_f64() / previous.as_secs_f64() - 1.0
+    };
+    return Some(Comparison {
+        baseline: previous,
+        change,
+        regression: change * 100.0 > threshold,
+    });
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    return if nanos < 1_000 {
+        format!("{}ns", nanos)
+    } else if nanos < 1_000_000 {
+        format!("{:.2}µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.2}ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2}s", nanos as f64 / 1e9)
+    };
+}
+
+pub fn render(measurements: &[Measurement], baseline: Option<&Baseline>, threshold: f64) -> String {
+    let rows: Vec<Vec<String>> = measurements
+        .iter()
+        .map(|measurement| {
+            let mut row = vec![
+                measurement.day.to_string(),
+                measurement.phase.to_string(),
+                format_duration(measurement.stats.min),
+                format_duration(measurement.stats.median),
+                format_duration(measurement.stats.p95),
+                format_duration(measurement.stats.mean),
+            ];
+            if let Some(baseline) = baseline {
+                match compare(measurement, baseline, threshold) {
+                    Some(comparison) => {
+                        row.push(format_duration(comparison.baseline));
+                        row.push(format!(
+                            "{:+.1}%{}",
+                            comparison.change * 100.0,
+                            if comparison.regression {
+                                " REGRESSION"
+                            } else {
+                                ""
+                            }
+                        ));
+                    }
+                    None => row.extend(["-".to_string(), "-".to_string()]),
+                }
+            }
+            row
+        })
+        .collect();
+
+    let mut headers = vec!["Day", "Phase", "Min", "Median", "P95", "Mean"];
+    if baseline.is_some() {
+        headers.extend(["Baseline", "Change"]);
+    }
+    return table::render(&headers, &rows);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{Endless, Panicking, Sum};
+
+    fn micros(values: &[u64]) -> Vec<Duration> {
+        return values.iter().map(|&v| Duration::from_micros(v)).collect();
+    }
+
+    fn measurement(day: u8, phase: Phase, median: u64) -> Measurement {
+        let median = Duration::from_micros(median);
+        return Measurement {
+            year: 2022,
+            day,
+            phase,
+            stats: Stats {
+                min: median,
+                median,
+                p95: median,
+                mean: median,
+            },
+        };
+    }
+
+    #[test]
+    fn should_compute_stats() {
+        let samples = micros(&[5, 1, 4, 2, 3, 100, 6, 7, 8, 9]);
+        assert_eq!(
+            Stats::from_samples(samples),
+            Some(Stats {
+                min: Duration::from_micros(1),
+                median: Duration::from_micros(5),
+                p95: Duration::from_micros(100),
+                mean: Duration::from_micros(145) / 10,
+            })
+        );
+        assert_eq!(Stats::from_samples(vec![]), None);
+    }
+
+    #[test]
+    fn should_time_parse_and_solved_parts_only() {
+        let phases: Vec<Phase> = bench_puzzle(&Sum, "1\n2", 3, runner::DEFAULT_TIMEOUT)
+            .unwrap()
+            .iter()
+            .map(|measurement| measurement.phase)
+            .collect();
+        assert_eq!(phases, vec![Phase::Parse, Phase::Solve(Part::One)]);
+        assert!(bench_puzzle(&Sum, "1\ntwo", 3, runner::DEFAULT_TIMEOUT).is_err());
+    }
+
+    #[test]
+    fn should_skip_a_panicking_part() {
+        let measurements = bench_puzzle(&Panicking, "", 3, runner::DEFAULT_TIMEOUT).unwrap();
+        let phases: Vec<Phase> = measurements.iter().map(|m| m.phase).collect();
+        assert_eq!(phases, vec![Phase::Parse]);
+    }
+
+    #[test]
+    fn should_stop_a_day_that_times_out() {
+        let timeout = Duration::from_millis(50);
+        assert_eq!(
+            bench_puzzle(&Endless, "", 3, timeout),
+            Err(AocError::TimedOut { after: timeout })
+        );
+    }
+
+    #[test]
+    fn should_round_trip_the_baseline() {
+        let measurements = vec![
+            measurement(7, Phase::Parse, 10),
+            measurement(7, Phase::Solve(Part::Two), 250),
+        ];
+        let baseline = Baseline::from_json(&Baseline::to_json(&measurements)).unwrap();
+        assert_eq!(
+            baseline.median(2022, 7, Phase::Solve(Part::Two)),
+            Some(Duration::from_micros(250))
+        );
+        assert_eq!(baseline.median(2022, 7, Phase::Solve(Part::One)), None);
+    }
+
+    #[test]
+    fn should_compare_a_day_with_the_same_day_of_its_year() {
+        let later = Measurement {
+            year: 2023,
+            ..measurement(7, Phase::Parse, 500)
+        };
+        let baseline = Baseline::from_json(&Baseline::to_json(&[
+            measurement(7, Phase::Parse, 100),
+            later,
+        ]))
+        .unwrap();
+        assert_eq!(
+            baseline.median(2022, 7, Phase::Parse),
+            Some(Duration::from_micros(100))
+        );
+        assert_eq!(
+            baseline.median(2023, 7, Phase::Parse),
+            Some(Duration::from_micros(500))
+        );
+
+        let faster = Measurement {
+            year: 2023,
+            ..measurement(7, Phase::Parse, 400)
+        };
+        assert!(!compare(&faster, &baseline, 10.0).unwrap().regression);
+        let unknown = Measurement {
+            year: 2021,
+            ..measurement(7, Phase::Parse, 100)
+        };
+        assert_eq!(compare(&unknown, &baseline, 10.0), None);
+    }
+
+    #[test]
+    fn should_flag_regressions_above_the_threshold() {
+        let baseline =
+            Baseline::from_json(&Baseline::to_json(&[measurement(10, Phase::Parse, 100)])).unwrap();
+
+        let slower = compare(&measurement(10, Phase::Parse, 125), &baseline, 10.0).unwrap();
+        assert!(slower.regression);
+        assert!((slower.change - 0.25).abs() < 1e-9);
+
+        let noise = compare(&measurement(10, Phase::Parse, 105), &baseline, 10.0).unwrap();
+        assert!(!noise.regression);
+
+        assert_eq!(
+            compare(&measurement(9, Phase::Parse, 100), &baseline, 10.0),
+            None
+        );
+    }
+
+    #[test]
+    fn should_format_durations() {
+        assert_eq!(format_duration(Duration::from_nanos(999)), "999ns");
+        assert_eq!(format_duration(Duration::from_nanos(1_500)), "1.50µs");
+        assert_eq!(format_duration(Duration::from_micros(2_345)), "2.35ms");
+        assert_eq!(format_duration(Duration::from_millis(1_200)), "1.20s");
+    }
+}
@@ -0,0 +1,640 @@
+use std::{path::PathBuf, str::FromStr, time::Duration};
+
+use aoc::{bench, generate, input, registry, report::Format, runner, solution::Part};
+
+pub const USAGE: &str = "Usage:
+    aoc run --day <N> [--part <1|2>] [--input <path>] [--trace]
+            [--format <text|json|csv>] [--timeout <seconds>] [--year <YYYY>]
+            [--profile <name>]
+    aoc run --all [--jobs <N>] [--trace] [--format <text|json|csv>]
+            [--timeout <seconds>] [--year <YYYY>] [--profile <name>]
+    aoc verify [--answers <path>] [--year <YYYY>]
+    aoc status [--answers <path>] [--year <YYYY>]
+    aoc bench [--day <N>] [--iterations <N>] [--baseline <path>]
+              [--save-baseline <path>] [--threshold <percent>] [--timeout <seconds>]
+              [--year <YYYY>]
+    aoc fetch --day <N> [--base-url <url>] [--year <YYYY>] [--profile <name>]
+    aoc submit --day <N> --part <1|2> [--base-url <url>] [--year <YYYY>]
+    aoc new-day <N> [--year <YYYY>]
+    aoc generate --day <N> [--seed <N>] [--size <N>] [--year <YYYY>]
+
+--year picks the year of the calendar, the latest one with solutions by
+default.
+
+Inputs are read from --input, stdin for --input -, then from
+$AOC_INPUT_DIR/YYYY/dayN.txt, then resources/YYYY/dayN.txt. Stdin is
+only read with --input -, never as a fallback, so an open pipe cannot
+block a run.
+
+--profile reads the inputs of another account, such as a team member's, from
+<profile>/YYYY/dayN.txt in the same directories, and fetch stores them there.
+Their answers go in the [<profile>.YYYY.dayN] tables of answers.toml. verify
+runs every profile found, so a solution that only works for one input fails.
+
+run --all solves the days on --jobs threads (default 1) and prints a summary
+of the answers, timings and failures.
+
+The parse step and each part are stopped after --timeout seconds (default
+60). A part that panics or times out fails on its own.
+
+Built with --features count-allocations, run also reports the allocations,
+the bytes allocated and the peak of live bytes of each step.
+
+--trace prints every step of the solutions on stderr. $AOC_TRACE=info or
+$AOC_TRACE=debug does the same for any command.
+
+status prints the calendar with the progress of every part: verified
+against the answers, answered but unverified, stubbed, or missing.
+
+bench times the parse step and each part --iterations times (default 100)
+and flags medians more than --threshold percent (default 10) slower than
+the --baseline. Each run is stopped after --timeout seconds, and a day that
+panics or times out fails. Build with --release for meaningful numbers.
+
+fetch downloads an input with the $AOC_SESSION cookie from --base-url,
+$AOC_BASE_URL or https://adventofcode.com, into $AOC_INPUT_DIR or
+resources/. Inputs already in either directory are never downloaded again.
+
+submit posts the answer computed on the day's input and records the verdict
+in submissions.toml. Answers known to be wrong are not sent again.
+
+new-day creates src/yYYYY/day_N.rs with stubs and ignored tests, empty
+resources/YYYY/dayN.txt and resources/YYYY/dayN-example.txt, and registers
+the day, and the year when it is the first day of a new one. Run it from the
+root of the repository.
+
+generate prints a random input for the day, the same for the same --seed
+(default 0), with about --size entries (default 100), and the answers it
+is known to have on stderr.";
+
+/// The `--input` reading the input from stdin.
+pub const STDIN: &str = "-";
+
+#[derive(Debug, PartialEq)]
+pub enum Selection {
+    All {
+        jobs: usize,
+    },
+    Day {
+        day: u8,
+        part: Option<Part>,
+        input: Option<PathBuf>,
+    },
+}
+
+#[derive(Debug, PartialEq)]
+pub struct BenchOptions {
+    pub year: u16,
+    pub day: Option<u8>,
+    pub iterations: u32,
+    /// Baseline to compare against.
+    pub baseline: Option<PathBuf>,
+    /// Where to write the measurements of this run.
+    pub save_baseline: Option<PathBuf>,
+    /// Allowed slowdown of the median, in percent.
+    pub threshold: f64,
+    /// Time allowed to each run of the parse step and of each part.
+    pub timeout: Duration,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        return BenchOptions {
+            year: registry::latest_year(),
+            day: None,
+            iterations: bench::DEFAULT_ITERATIONS,
+            baseline: None,
+            save_baseline: None,
+            threshold: bench::DEFAULT_THRESHOLD,
+            timeout: runner::DEFAULT_TIMEOUT,
+        };
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Run {
+        year: u16,
+        selection: Selection,
+        trace: bool,
+        format: Format,
+        /// Time allowed to the parse step and to each part.
+        timeout: Duration,
+        profile: Option<String>,
+    },
+    Verify {
+        year: u16,
+        answers: Option<PathBuf>,
+    },
+    Status {
+        year: u16,
+        answers: Option<PathBuf>,
+    },
+    Bench(BenchOptions),
+    Fetch {
+        year: u16,
+        day: u8,
+        base_url: Option<String>,
+        profile: Option<String>,
+    },
+    Submit {
+        year: u16,
+        day: u8,
+        part: Part,
+        base_url: Option<String>,
+    },
+    NewDay {
+        year: u16,
+        day: u8,
+    },
+    Generate {
+        year: u16,
+        day: u8,
+        seed: u64,
+        size: usize,
+    },
+    Help,
+}
+
+fn parse_value<T: FromStr>(flag: &str, value: Option<String>) -> Result<T, String> {
+    let value = value.ok_or_else(|| format!("Missing value for {}", flag))?;
+    return value
+        .parse::<T>()
+        .map_err(|_| format!("Invalid value for {}: {}", flag, value));
+}
+
+fn parse_number(flag: &str, value: Option<String>) -> Result<u8, String> {
+    return parse_value(flag, value);
+}
+
+fn parse_year(value: Option<String>) -> Result<u16, String> {
+    return parse_value("--year", value);
+}
+
+fn parse_profile(value: Option<String>) -> Result<String, String> {
+    let profile = value.ok_or("Missing value for --profile")?;
+    if !input::is_profile_name(&profile) {
+        return Err(format!("Invalid value for --profile: {}", profile));
+    }
+    return Ok(profile);
+}
+
+fn parse_part(value: Option<String>) -> Result<Part, String> {
+    let number = parse_number("--part", value)?;
+    return Part::from_number(number)
+        .ok_or_else(|| format!("Invalid value for --part: {}", number));
+}
+
+fn parse_timeout(value: Option<String>) -> Result<Duration, String> {
+    let seconds: f64 = parse_value("--timeout", value)?;
+    return Duration::try_from_secs_f64(seconds)
+        .ok()
+        .filter(|timeout| !timeout.is_zero())
+        .ok_or_else(|| format!("Invalid value for --timeout: {}", seconds));
+}
+
+fn parse_path(flag: &str, value: Option<String>) -> Result<PathBuf, String> {
+    return value
+        .map(PathBuf::from)
+        .ok_or_else(|| format!("Missing value for {}", flag));
+}
+
+fn parse_run(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut year = registry::latest_year();
+    let mut all = false;
+    let mut trace = false;
+    let mut format = Format::default();
+    let mut jobs = None;
+    let mut timeout = runner::DEFAULT_TIMEOUT;
+    let mut day = None;
+    let mut part = None;
+    let mut input = None;
+    let mut profile = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--year" => year = parse_year(args.next())?,
+            "--all" => all = true,
+            "--trace" => trace = true,
+            "--format" => format = parse_value("--format", args.next())?,
+            "--timeout" => timeout = parse_timeout(args.next())?,
+            "--jobs" => jobs = Some(parse_value::<usize>("--jobs", args.next())?),
+            "--day" => day = Some(parse_number("--day", args.next())?),
+            "--part" => part = Some(parse_part(args.next())?),
+            "--input" => input = Some(parse_path("--input", args.next())?),
+            "--profile" => profile = Some(parse_profile(args.next())?),
+            unknown => return Err(format!("Unknown argument {}", unknown)),
+        }
+    }
+
+    if jobs == Some(0) {
+        return Err("--jobs must be at least 1".to_string());
+    }
+    if input.is_some() && profile.is_some() {
+        return Err("--profile cannot be combined with --input".to_string());
+    }
+    let selection = match (all, day) {
+        (true, None) if part.is_none() && input.is_none() => Selection::All {
+            jobs: jobs.unwrap_or(1),
+        },
+        (true, _) => {
+            return Err("--all cannot be combined with --day, --part or --input".to_string())
+        }
+        (false, Some(_)) if jobs.is_some() => {
+            return Err("--jobs only applies to --all".to_string())
+        }
+        (false, Some(day)) => Selection::Day { day, part, input },
+        (false, None) => return Err("Either --day or --all is required".to_string()),
+    };
+    return Ok(Command::Run {
+        year,
+        selection,
+        trace,
+        format,
+        timeout,
+        profile,
+    });
+}
+
+/// The year and the answers file of `verify` and `status`.
+fn parse_answers(mut args: impl Iterator<Item = String>) -> Result<(u16, Option<PathBuf>), String> {
+    let mut year = registry::latest_year();
+    let mut answers = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--year" => year = parse_year(args.next())?,
+            "--answers" => answers = Some(parse_path("--answers", args.next())?),
+            unknown => return Err(format!("Unknown argument {}", unknown)),
+        }
+    }
+    return Ok((year, answers));
+}
+
+fn parse_fetch(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut year = registry::latest_year();
+    let mut day = None;
+    let mut base_url = None;
+    let mut profile = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--year" => year = parse_year(args.next())?,
+            "--day" => day = Some(parse_number("--day", args.next())?),
+            "--base-url" => base_url = Some(parse_value("--base-url", args.next())?),
+            "--profile" => profile = Some(parse_profile(args.next())?),
+            unknown => return Err(format!("Unknown argument {}", unknown)),
+        }
+    }
+    let day = day.ok_or("--day is required")?;
+    return Ok(Command::Fetch {
+        year,
+        day,
+        base_url,
+        profile,
+    });
+}
+
+fn parse_submit(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut year = registry::latest_year();
+    let mut day = None;
+    let mut part = None;
+    let mut base_url = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--year" => year = parse_year(args.next())?,
+            "--day" => day = Some(parse_number("--day", args.next())?),
+            "--part" => part = Some(parse_part(args.next())?),
+            "--base-url" => base_url = Some(parse_value("--base-url", args.next())?),
+            unknown => return Err(format!("Unknown argument {}", unknown)),
+        }
+    }
+    return match (day, part) {
+        (Some(day), Some(part)) => Ok(Command::Submit {
+            year,
+            day,
+            part,
+            base_url,
+        }),
+        _ => Err("--day and --part are required".to_string()),
+    };
+}
+
+fn parse_bench(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut options = BenchOptions::default();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--year" => options.year = parse_year(args.next())?,
+            "--day" => options.day = Some(parse_number("--day", args.next())?),
+            "--iterations" => options.iterations = parse_value("--iterations", args.next())?,
+            "--baseline" => options.baseline = Some(parse_path("--baseline", args.next())?),
+            "--save-baseline" => {
+                options.save_baseline = Some(parse_path("--save-baseline", args.next())?)
+            }
+            "--threshold" => options.threshold = parse_value("--threshold", args.next())?,
+            "--timeout" => options.timeout = parse_timeout(args.next())?,
+            unknown => return Err(format!("Unknown argument {}", unknown)),
+        }
+    }
+    if options.iterations == 0 {
+        return Err("--iterations must be at least 1".to_string());
+    }
+    return Ok(Command::Bench(options));
+}
+
+fn parse_new_day(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let day = parse_number("new-day", args.next())?;
+    let mut year = registry::latest_year();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--year" => year = parse_year(args.next())?,
+            unknown => return Err(format!("Unknown argument {}", unknown)),
+        }
+    }
+    return Ok(Command::NewDay { year, day });
+}
+
+fn parse_generate(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut year = registry::latest_year();
+    let mut day = None;
+    let mut seed = 0;
+    let mut size = generate::DEFAULT_SIZE;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" => day = Some(parse_number("--day", args.next())?),
+            "--year" => year = parse_year(args.next())?,
+            "--seed" => seed = parse_value("--seed", args.next())?,
+            "--size" => size = parse_value("--size", args.next())?,
+            unknown => return Err(format!("Unknown argument {}", unknown)),
+        }
+    }
+    let day = day.ok_or("--day is required")?;
+    return Ok(Command::Generate {
+        year,
+        day,
+        seed,
+        size,
+    });
+}
+
+pub fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    return match args.next().as_deref() {
+        Some("run") => parse_run(args),
+        Some("verify") => {
+            parse_answers(args).map(|(year, answers)| Command::Verify { year, answers })
+        }
+        Some("status") => {
+            parse_answers(args).map(|(year, answers)| Command::Status { year, answers })
+        }
+        Some("bench") => parse_bench(args),
+        Some("fetch") => parse_fetch(args),
+        Some("submit") => parse_submit(args),
+        Some("new-day") => parse_new_day(args),
+        Some("generate") => parse_generate(args),
+        Some("help" | "--help" | "-h") | None => Ok(Command::Help),
+        Some(unknown) => Err(format!("Unknown command {}", unknown)),
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(line: &str) -> Result<Command, String> {
+        return parse_args(line.split_whitespace().map(|s| s.to_string()));
+    }
+
+    #[test]
+    fn should_parse_a_single_day() {
+        assert_eq!(
+            parse("run --day 7 --part 2 --input some/path.txt"),
+            Ok(Command::Run {
+                year: registry::latest_year(),
+                selection: Selection::Day {
+                    day: 7,
+                    part: Some(Part::Two),
+                    input: Some(PathBuf::from("some/path.txt")),
+                },
+                trace: false,
+                format: Format::Text,
+                timeout: runner::DEFAULT_TIMEOUT,
+                profile: None,
+            })
+        );
+    }
+
+    #[test]
+    fn should_parse_stdin_as_an_input() {
+        assert_eq!(
+            parse("run --day 7 --input -"),
+            Ok(Command::Run {
+                year: registry::latest_year(),
+                selection: Selection::Day {
+                    day: 7,
+                    part: None,
+                    input: Some(PathBuf::from(STDIN)),
+                },
+                trace: false,
+                format: Format::Text,
+                timeout: runner::DEFAULT_TIMEOUT,
+                profile: None,
+            })
+        );
+    }
+
+    #[test]
+    fn should_parse_all() {
+        assert_eq!(
+            parse("run --all --trace --format json"),
+            Ok(Command::Run {
+                year: registry::latest_year(),
+                selection: Selection::All { jobs: 1 },
+                trace: true,
+                format: Format::Json,
+                timeout: runner::DEFAULT_TIMEOUT,
+                profile: None,
+            })
+        );
+        assert_eq!(
+            parse("run --all --jobs 4 --timeout 2.5 --year 2021"),
+            Ok(Command::Run {
+                year: 2021,
+                selection: Selection::All { jobs: 4 },
+                trace: false,
+                format: Format::Text,
+                timeout: Duration::from_millis(2500),
+                profile: None,
+            })
+        );
+    }
+
+    #[test]
+    fn should_parse_a_profile() {
+        assert_eq!(
+            parse("run --all --profile alice"),
+            Ok(Command::Run {
+                year: registry::latest_year(),
+                selection: Selection::All { jobs: 1 },
+                trace: false,
+                format: Format::Text,
+                timeout: runner::DEFAULT_TIMEOUT,
+                profile: Some("alice".to_string()),
+            })
+        );
+        assert!(parse("run --day 7 --profile").is_err());
+        assert!(parse("run --day 7 --profile 2022").is_err());
+        assert!(parse("run --day 7 --profile ../bob").is_err());
+        assert!(parse("run --day 7 --profile alice --input day7.txt").is_err());
+    }
+
+    #[test]
+    fn should_parse_verify() {
+        assert_eq!(
+            parse("verify"),
+            Ok(Command::Verify {
+                year: registry::latest_year(),
+                answers: None
+            })
+        );
+        assert_eq!(
+            parse("verify --answers other.toml --year 2021"),
+            Ok(Command::Verify {
+                year: 2021,
+                answers: Some(PathBuf::from("other.toml"))
+            })
+        );
+    }
+
+    #[test]
+    fn should_parse_status() {
+        assert_eq!(
+            parse("status"),
+            Ok(Command::Status {
+                year: registry::latest_year(),
+                answers: None
+            })
+        );
+        assert_eq!(
+            parse("status --year 2021 --answers other.toml"),
+            Ok(Command::Status {
+                year: 2021,
+                answers: Some(PathBuf::from("other.toml"))
+            })
+        );
+        assert!(parse("status --day 7").is_err());
+    }
+
+    #[test]
+    fn should_parse_bench() {
+        assert_eq!(parse("bench"), Ok(Command::Bench(BenchOptions::default())));
+        assert_eq!(
+            parse("bench --day 7 --iterations 20 --baseline old.json --threshold 5 --timeout 2"),
+            Ok(Command::Bench(BenchOptions {
+                year: registry::latest_year(),
+                day: Some(7),
+                iterations: 20,
+                baseline: Some(PathBuf::from("old.json")),
+                save_baseline: None,
+                threshold: 5.0,
+                timeout: Duration::from_secs(2),
+            }))
+        );
+    }
+
+    #[test]
+    fn should_parse_fetch() {
+        assert_eq!(
+            parse("fetch --day 11 --base-url http://localhost:8080 --year 2020"),
+            Ok(Command::Fetch {
+                year: 2020,
+                day: 11,
+                base_url: Some("http://localhost:8080".to_string()),
+                profile: None,
+            })
+        );
+        assert_eq!(
+            parse("fetch --day 11 --profile alice"),
+            Ok(Command::Fetch {
+                year: registry::latest_year(),
+                day: 11,
+                base_url: None,
+                profile: Some("alice".to_string()),
+            })
+        );
+        assert!(parse("fetch").is_err());
+    }
+
+    #[test]
+    fn should_parse_submit() {
+        assert_eq!(
+            parse("submit --day 7 --part 2"),
+            Ok(Command::Submit {
+                year: registry::latest_year(),
+                day: 7,
+                part: Part::Two,
+                base_url: None,
+            })
+        );
+        assert!(parse("submit --day 7").is_err());
+    }
+
+    #[test]
+    fn should_parse_new_day() {
+        assert_eq!(
+            parse("new-day 4"),
+            Ok(Command::NewDay {
+                year: registry::latest_year(),
+                day: 4
+            })
+        );
+        assert_eq!(
+            parse("new-day 1 --year 2023"),
+            Ok(Command::NewDay { year: 2023, day: 1 })
+        );
+        assert!(parse("new-day").is_err());
+        assert!(parse("new-day four").is_err());
+        assert!(parse("new-day 4 5").is_err());
+        assert!(parse("new-day 4 --year").is_err());
+    }
+
+    #[test]
+    fn should_parse_generate() {
+        assert_eq!(
+            parse("generate --day 7 --seed 42 --size 10000"),
+            Ok(Command::Generate {
+                year: registry::latest_year(),
+                day: 7,
+                seed: 42,
+                size: 10000
+            })
+        );
+        assert_eq!(
+            parse("generate --day 1"),
+            Ok(Command::Generate {
+                year: registry::latest_year(),
+                day: 1,
+                seed: 0,
+                size: generate::DEFAULT_SIZE
+            })
+        );
+        assert!(parse("generate --seed 1").is_err());
+        assert!(parse("generate --day 1 --size big").is_err());
+    }
+
+    #[test]
+    fn should_reject_invalid_arguments() {
+        assert!(parse("run").is_err());
+        assert!(parse("run --day").is_err());
+        assert!(parse("run --day 7 --part 3").is_err());
+        assert!(parse("run --all --day 7").is_err());
+        assert!(parse("run --day 7 --verbose").is_err());
+        assert!(parse("run --all --format xml").is_err());
+        assert!(parse("run --all --jobs 0").is_err());
+        assert!(parse("run --all --timeout 0").is_err());
+        assert!(parse("run --all --timeout -1").is_err());
+        assert!(parse("run --day 7 --jobs 2").is_err());
+        assert!(parse("bench --iterations 0").is_err());
+        assert!(parse("bench --threshold fast").is_err());
+        assert!(parse("run --all --year 99999").is_err());
+        assert!(parse("run --all --year").is_err());
+        assert!(parse("walk").is_err());
+    }
+}
@@ -0,0 +1,195 @@
+//! Differential testing: the solutions are run next to their naive reference
+//! on generated inputs, and an input they disagree on is shrunk to a minimal
+//! one before being reported.
+
+use crate::{
+    error::AocError,
+    runner::catch_panic,
+    solution::{Answer, Part, Puzzle},
+};
+
+/// An input the solution and the reference answer differently.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Disagreement {
+    pub day: u8,
+    pub part: Part,
+    pub input: String,
+    pub solution: Result<Answer, AocError>,
+    pub reference: Result<Answer, AocError>,
+}
+
+/// A panic is an answer like any other error.
+fn guarded(step: impl FnOnce() -> Result<Answer, AocError>) -> Result<Answer, AocError> {
+    return catch_panic(step).unwrap_or_else(|message| Err(AocError::Panicked(message)));
+}
+
+/// `None` when both agree, when the input does not parse or when one of them
+/// is not implemented. Errors all count as no answer, whatever their message.
+pub fn compare(puzzle: &dyn Puzzle, part: Part, input: &str) -> Option<Disagreement> {
+    let parsed = puzzle.parse(input).ok()?;
+    let solution = guarded(|| puzzle.solve(part, parsed.as_ref()));
+    let reference = guarded(|| puzzle.solve_reference(part, parsed.as_ref()));
+    let not_implemented =
+        |result: &Result<Answer, AocError>| matches!(result, Err(AocError::NotImplemented { .. }));
+    if not_implemented(&solution)
+        || not_implemented(&reference)
+        || solution.as_ref().ok() == reference.as_ref().ok()
+    {
+        return None;
+    }
+    return Some(Disagreement {
+        day: puzzle.day(),
+        part,
+        input: input.to_string(),
+        solution,
+        reference,
+    });
+}
+
+/// The inputs made by removing a run of lines from `input`, the longest runs
+/// first. A single line loses characters instead.
+fn smaller(input: &str) -> Vec<String> {
+    let lines: Vec<&str> = input.split('\n').collect();
+    let (units, separator): (Vec<&str>, &str) = if lines.len() > 1 {
+        (lines, "\n")
+    } else {
+        (input.split_inclusive(|_| true).collect(), "")
+    };
+    let mut candidates = Vec::new();
+    let mut length = units.len() / 2;
+    while length > 0 {
+        for start in (0..=units.len() - length).step_by(length) {
+            let kept: Vec<&str> = units[..start]
+                .iter()
+                .chain(&units[start + length..])
+                .copied()
+                .collect();
+            candidates.push(kept.join(separator));
+        }
+        length /= 2;
+    }
+    return candidates;
+}
+
+/// Removes lines, then characters, for as long as the disagreement remains.
+pub fn shrink(puzzle: &dyn Puzzle, mut disagreement: Disagreement) -> Disagreement {
+    while let Some(smaller) = smaller(&disagreement.input)
+        .iter()
+        .find_map(|candidate| compare(puzzle, disagreement.part, candidate))
+    {
+        disagreement = smaller;
+    }
+    return disagreement;
+}
+
+/// Compares both parts on the inputs generated from `seeds`, of sizes growing
+/// up to `max_size`, and returns the first disagreement of each part, shrunk.
+pub fn check(
+    puzzle: &dyn Puzzle,
+    seeds: std::ops::Range<u64>,
+    max_size: usize,
+) -> Vec<Disagreement> {
+    let mut disagreements = Vec::new();
+    for part in Part::ALL {
+        let found = seeds.clone().find_map(|seed| {
+            let size = 1 + seed as usize % max_size;
+            let generated = puzzle.generate(seed, size)?;
+            return compare(puzzle, part, &generated.input);
+        });
+        if let Some(disagreement) = found {
+            disagreements.push(shrink(puzzle, disagreement));
+        }
+    }
+    return disagreements;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        generate::{Generated, Rng},
+        registry,
+        solution::Solution,
+    };
+
+    /// Sums numbers, but loses the 7s.
+    struct Sloppy;
+
+    impl Solution for Sloppy {
+        type Input = Vec<u32>;
+
+        const YEAR: u16 = 2022;
+
+        const DAY: u8 = 42;
+
+        fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+            return input
+                .lines()
+                .map(|line| line.parse().map_err(|_| AocError::parse(line)))
+                .collect();
+        }
+
+        fn part1(&self, numbers: &Self::Input) -> Result<Answer, AocError> {
+            return Ok(numbers.iter().filter(|&&n| n != 7).sum::<u32>().into());
+        }
+
+        fn reference(&self, part: Part, numbers: &Self::Input) -> Result<Answer, AocError> {
+            return match part {
+                Part::One => Ok(numbers.iter().sum::<u32>().into()),
+                Part::Two => Err(AocError::NotImplemented { day: 42, part }),
+            };
+        }
+
+        fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+            let numbers: Vec<String> = (0..size).map(|_| rng.range(1, 9).to_string()).collect();
+            return Some(Generated {
+                input: numbers.join("\n"),
+                part1: None,
+                part2: None,
+            });
+        }
+    }
+
+    #[test]
+    fn should_shrink_to_a_minimal_input() {
+        let disagreements = check(&Sloppy, 0..20, 50);
+        assert_eq!(
+            disagreements,
+            vec![Disagreement {
+                day: 42,
+                part: Part::One,
+                input: "7".to_string(),
+                solution: Ok(0.into()),
+                reference: Ok(7.into()),
+            }]
+        );
+    }
+
+    #[test]
+    fn should_remove_runs_of_lines_then_characters() {
+        assert_eq!(smaller("a\nb\nc"), vec!["b\nc", "a\nc", "a\nb"]);
+        assert_eq!(
+            smaller("abcd"),
+            vec!["cd", "ab", "bcd", "acd", "abd", "abc"]
+        );
+    }
+
+    #[test]
+    fn should_agree_with_the_references() {
+        for puzzle in registry::all() {
+            let disagreements = check(puzzle, 0..100, 30);
+            assert!(
+                disagreements.is_empty(),
+                "{}",
+                disagreements
+                    .iter()
+                    .map(|d| format!(
+                        "day {} part {}: {:?} instead of {:?} for\n{}",
+                        d.day, d.part, d.solution, d.reference, d.input
+                    ))
+                    .collect::<Vec<String>>()
+                    .join("\n\n")
+            );
+        }
+    }
+}
@@ -0,0 +1,213 @@
+use std::{error::Error, fmt, path::PathBuf, time::Duration};
+
+use itertools::Itertools;
+
+use crate::solution::Part;
+
+/// A position in an input, both counted from 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+    /// The whole line, as written in the input.
+    pub text: String,
+}
+
+/// What is wrong with an input, and where when it is known.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub message: String,
+    pub location: Option<Location>,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.location {
+            Some(Location { line, column, .. }) => {
+                write!(f, "line {}, column {}: {}", line, column, self.message)
+            }
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl ParseError {
+    /// The error laid out as a compiler does, the offending line being shown
+    /// with a caret under the column. `origin` names the input.
+    pub fn diagnostic(&self, origin: &str) -> String {
+        let location = match &self.location {
+            Some(location) => location,
+            None => return format!("error: {}\n --> {}", self.message, origin),
+        };
+        let gutter = " ".repeat(location.line.to_string().len());
+        return [
+            format!("error: {}", self.message),
+            format!(
+                "{}--> {}:{}:{}",
+                gutter, origin, location.line, location.column
+            ),
+            format!("{} |", gutter),
+            format!("{} | {}", location.line, location.text),
+            format!("{} | {}^", gutter, " ".repeat(location.column - 1)),
+        ]
+        .join("\n");
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AocError {
+    /// The input does not follow the puzzle grammar.
+    Parse(ParseError),
+    /// The input is well formed but breaks a rule of the puzzle.
+    Validation(String),
+    /// The input is valid but takes a path the solution does not handle.
+    UnsupportedInput(String),
+    /// The part has not been solved yet.
+    NotImplemented { day: u8, part: Part },
+    /// No input could be found for the day.
+    MissingInput {
+        year: u16,
+        day: u8,
+        searched: Vec<PathBuf>,
+    },
+    /// An input exists but could not be read.
+    Io(String),
+    /// The puzzle of the day is not available on the website yet.
+    NotUnlocked { day: u8 },
+    /// The website could not be reached or answered with an error.
+    Http(String),
+    /// An answer was not submitted because of what is already known about it.
+    Refused(String),
+    /// The solution panicked; holds the panic message.
+    Panicked(String),
+    /// The solution was still running when its time was up.
+    TimedOut { after: Duration },
+}
+
+impl AocError {
+    pub fn parse(message: impl Into<String>) -> Self {
+        return AocError::Parse(ParseError {
+            message: message.into(),
+            location: None,
+        });
+    }
+
+    pub fn validation(message: impl Into<String>) -> Self {
+        return AocError::Validation(message.into());
+    }
+
+    pub fn unsupported(message: impl Into<String>) -> Self {
+        return AocError::UnsupportedInput(message.into());
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::Parse(
+                error @ ParseError {
+                    location: Some(_), ..
+                },
+            ) => write!(f, "parse error at {}", error),
+            AocError::Parse(error) => write!(f, "parse error: {}", error),
+            AocError::Validation(message) => write!(f, "invalid input: {}", message),
+            AocError::UnsupportedInput(message) => write!(f, "unsupported input: {}", message),
+            AocError::NotImplemented { day, part } => {
+                write!(f, "day {} part {} is not implemented", day, part)
+            }
+            AocError::MissingInput {
+                year,
+                day,
+                searched,
+            } => write!(
+                f,
+                "no input for day {} of {} (looked in {})",
+                day,
+                year,
+                searched.iter().map(|path| path.display()).join(", ")
+            ),
+            AocError::Io(message) => write!(f, "{}", message),
+            AocError::NotUnlocked { day } => write!(f, "day {} is not unlocked yet", day),
+            AocError::Http(message) => write!(f, "http error: {}", message),
+            AocError::Refused(message) => write!(f, "not submitted: {}", message),
+            AocError::Panicked(message) => write!(f, "panicked: {}", message),
+            AocError::TimedOut { after } => {
+                write!(f, "timed out after {}s", after.as_secs_f64())
+            }
+        }
+    }
+}
+
+impl Error for AocError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_display_errors() {
+        assert_eq!(
+            AocError::parse("unexpected token").to_string(),
+            "parse error: unexpected token"
+        );
+        assert_eq!(
+            AocError::Parse(ParseError {
+                message: "expected a number, found \"x\"".to_string(),
+                location: Some(Location {
+                    line: 3,
+                    column: 7,
+                    text: "move 3x".to_string()
+                })
+            })
+            .to_string(),
+            "parse error at line 3, column 7: expected a number, found \"x\""
+        );
+        assert_eq!(
+            AocError::NotImplemented {
+                day: 8,
+                part: Part::Two
+            }
+            .to_string(),
+            "day 8 part 2 is not implemented"
+        );
+        assert_eq!(
+            AocError::MissingInput {
+                year: 2022,
+                day: 4,
+                searched: vec![
+                    PathBuf::from("inputs/2022/day4.txt"),
+                    PathBuf::from("resources/2022/day4.txt")
+                ]
+            }
+            .to_string(),
+            "no input for day 4 of 2022 (looked in inputs/2022/day4.txt, resources/2022/day4.txt)"
+        );
+        assert_eq!(
+            AocError::TimedOut {
+                after: Duration::from_millis(1500)
+            }
+            .to_string(),
+            "timed out after 1.5s"
+        );
+    }
+
+    #[test]
+    fn should_point_to_the_column() {
+        let error = ParseError {
+            message: "expected a letter, found \"1x\"".to_string(),
+            location: Some(Location {
+                line: 12,
+                column: 4,
+                text: "abc1x".to_string(),
+            }),
+        };
+        assert_eq!(
+            error.diagnostic("day 3 input"),
+            "error: expected a letter, found \"1x\"
+  --> day 3 input:12:4
+   |
+12 | abc1x
+   |    ^"
+        );
+    }
+}
@@ -0,0 +1,74 @@
+//! Solutions standing in for the days in the tests.
+
+use std::{thread, time::Duration};
+
+use crate::{
+    error::AocError,
+    solution::{Answer, Solution},
+};
+
+/// Sums one number per line in part 1, part 2 is not implemented.
+pub struct Sum;
+
+impl Solution for Sum {
+    type Input = Vec<u32>;
+
+    const YEAR: u16 = 2022;
+
+    const DAY: u8 = 1;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        return input
+            .lines()
+            .map(|n| n.parse::<u32>().map_err(|e| AocError::parse(e.to_string())))
+            .collect();
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer, AocError> {
+        return Ok(input.iter().sum::<u32>().into());
+    }
+}
+
+/// Panics with `not yet` in part 1.
+pub struct Panicking;
+
+impl Solution for Panicking {
+    type Input = ();
+
+    const YEAR: u16 = 2022;
+
+    const DAY: u8 = 2;
+
+    fn parse(&self, _input: &str) -> Result<Self::Input, AocError> {
+        return Ok(());
+    }
+
+    fn part1(&self, _input: &Self::Input) -> Result<Answer, AocError> {
+        panic!("not yet");
+    }
+}
+
+/// Never returns from part 1, answers 2 in part 2.
+pub struct Endless;
+
+impl Solution for Endless {
+    type Input = ();
+
+    const YEAR: u16 = 2022;
+
+    const DAY: u8 = 3;
+
+    fn parse(&self, _input: &str) -> Result<Self::Input, AocError> {
+        return Ok(());
+    }
+
+    fn part1(&self, _input: &Self::Input) -> Result<Answer, AocError> {
+        loop {
+            thread::sleep(Duration::from_millis(10));
+        }
+    }
+
+    fn part2(&self, _input: &Self::Input) -> Result<Answer, AocError> {
+        return Ok(2.into());
+    }
+}
@@ -0,0 +1,140 @@
+//! Random puzzle inputs built from a seed, with the answers the generators
+//! compute on their own. They stand in for the private inputs in the tests and
+//! make inputs of any size for stress testing.
+
+use crate::solution::{Answer, Part};
+
+/// Size of the generated inputs when none is given, close to the real ones.
+pub const DEFAULT_SIZE: usize = 100;
+
+/// SplitMix64: small, fast, and the same sequence for a seed on every platform.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        return Rng { state: seed };
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        return z ^ (z >> 31);
+    }
+
+    /// A number from `low` to `high`, both included.
+    pub fn range(&mut self, low: u64, high: u64) -> u64 {
+        return low + self.next_u64() % (high - low + 1);
+    }
+
+    /// An index below `len`, which must not be zero.
+    pub fn below(&mut self, len: usize) -> usize {
+        return self.range(0, len as u64 - 1) as usize;
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        return &items[self.below(items.len())];
+    }
+
+    /// True once in `times` draws on average.
+    pub fn one_in(&mut self, times: u64) -> bool {
+        return self.range(1, times) == 1;
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for index in (1..items.len()).rev() {
+            items.swap(index, self.below(index + 1));
+        }
+    }
+}
+
+/// An input and its answers, `None` for the parts the generator cannot tell.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Generated {
+    pub input: String,
+    pub part1: Option<Answer>,
+    pub part2: Option<Answer>,
+}
+
+impl Generated {
+    pub fn answer(&self, part: Part) -> Option<&Answer> {
+        return match part {
+            Part::One => self.part1.as_ref(),
+            Part::Two => self.part2.as_ref(),
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        answers::{Answers, ANSWERS_FILE},
+        error::AocError,
+        registry,
+    };
+    use std::path::Path;
+
+    #[test]
+    fn should_repeat_the_sequence_of_a_seed() {
+        let draws = |seed| -> Vec<u64> {
+            let mut rng = Rng::new(seed);
+            return (0..5).map(|_| rng.range(1, 6)).collect();
+        };
+        assert_eq!(draws(7), draws(7));
+        assert_ne!(draws(7), draws(8));
+        assert!(draws(7).iter().all(|draw| (1..=6).contains(draw)));
+    }
+
+    /// Days fresh out of `new-day` have no generator yet.
+    #[test]
+    fn should_generate_an_input_for_every_solved_day() {
+        let answers = Answers::load(Path::new(ANSWERS_FILE)).unwrap();
+        let solved = registry::all().filter(|puzzle| {
+            answers
+                .get(None, puzzle.year(), puzzle.day(), Part::One)
+                .is_some()
+        });
+        for puzzle in solved {
+            assert!(
+                puzzle.generate(1, 10).is_some(),
+                "day {} has no generator",
+                puzzle.day()
+            );
+        }
+    }
+
+    #[test]
+    fn should_solve_generated_inputs() {
+        for puzzle in registry::all() {
+            for seed in 0..20 {
+                let generated = match puzzle.generate(seed, 1 + seed as usize) {
+                    Some(generated) => generated,
+                    None => continue,
+                };
+                for part in [Part::One, Part::Two] {
+                    let expected = match generated.answer(part) {
+                        Some(expected) => expected,
+                        None => continue,
+                    };
+                    match puzzle.run(part, &generated.input) {
+                        Err(AocError::NotImplemented { .. }) => {}
+                        answer => assert_eq!(
+                            answer.as_ref(),
+                            Ok(expected),
+                            "day {} part {} with seed {}:\n{}",
+                            puzzle.day(),
+                            part,
+                            seed,
+                            generated.input
+                        ),
+                    }
+                }
+            }
+        }
+    }
+}
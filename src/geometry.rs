@@ -0,0 +1,260 @@
+//! Points and directions on the plane, `y` growing downwards as the rows of a
+//! puzzle input do: `Up` is `(0, -1)` and turning right goes clockwise.
+
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        return Point2 { x, y };
+    }
+}
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        return Point2 { x, y };
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point2<T> {
+    type Output = Point2<T>;
+
+    fn add(self, other: Self) -> Self::Output {
+        return Point2::new(self.x + other.x, self.y + other.y);
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point2<T> {
+    type Output = Point2<T>;
+
+    fn sub(self, other: Self) -> Self::Output {
+        return Point2::new(self.x - other.x, self.y - other.y);
+    }
+}
+
+impl<T: AddAssign> AddAssign for Point2<T> {
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Point2<T> {
+    fn sub_assign(&mut self, other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point2<T> {
+    type Output = Point2<T>;
+
+    fn neg(self) -> Self::Output {
+        return Point2::new(-self.x, -self.y);
+    }
+}
+
+/// Scaling by a factor.
+impl<T: Mul<Output = T> + Copy> Mul<T> for Point2<T> {
+    type Output = Point2<T>;
+
+    fn mul(self, factor: T) -> Self::Output {
+        return Point2::new(self.x * factor, self.y * factor);
+    }
+}
+
+impl<T: Neg<Output = T>> Point2<T> {
+    /// A quarter turn clockwise around the origin.
+    pub fn rotate_right(self) -> Self {
+        return Point2::new(-self.y, self.x);
+    }
+
+    /// A quarter turn counterclockwise around the origin.
+    pub fn rotate_left(self) -> Self {
+        return Point2::new(self.y, -self.x);
+    }
+}
+
+macro_rules! signed_point {
+    ($($number:ty),*) => {
+        $(impl Point2<$number> {
+            /// The step of at most one in each axis going towards `self`.
+            pub fn signum(self) -> Self {
+                return Point2::new(self.x.signum(), self.y.signum());
+            }
+
+            /// Number of moves along the axes from one point to the other.
+            pub fn manhattan(self, other: Self) -> $number {
+                return (self.x - other.x).abs() + (self.y - other.y).abs();
+            }
+
+            /// Number of moves when diagonal moves are allowed too.
+            pub fn chebyshev(self, other: Self) -> $number {
+                return (self.x - other.x).abs().max((self.y - other.y).abs());
+            }
+
+            /// Length of the straight line between the points.
+            pub fn euclidean(self, other: Self) -> f64 {
+                return ((self.x - other.x) as f64).hypot((self.y - other.y) as f64);
+            }
+        })*
+    };
+}
+
+signed_point!(i32, i64, isize);
+
+/// The four directions along the axes, in clockwise order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction4 {
+    pub const ALL: [Direction4; 4] = [
+        Direction4::Up,
+        Direction4::Right,
+        Direction4::Down,
+        Direction4::Left,
+    ];
+
+    /// The move of one step in this direction.
+    pub fn offset<T: From<i8>>(self) -> Point2<T> {
+        let (x, y) = match self {
+            Direction4::Up => (0, -1),
+            Direction4::Right => (1, 0),
+            Direction4::Down => (0, 1),
+            Direction4::Left => (-1, 0),
+        };
+        return Point2::new(T::from(x), T::from(y));
+    }
+
+    pub fn turn_right(self) -> Self {
+        return Direction4::ALL[(self as usize + 1) % 4];
+    }
+
+    pub fn turn_left(self) -> Self {
+        return Direction4::ALL[(self as usize + 3) % 4];
+    }
+
+    pub fn opposite(self) -> Self {
+        return Direction4::ALL[(self as usize + 2) % 4];
+    }
+}
+
+/// The eight directions around a point, in clockwise order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    pub const ALL: [Direction8; 8] = [
+        Direction8::Up,
+        Direction8::UpRight,
+        Direction8::Right,
+        Direction8::DownRight,
+        Direction8::Down,
+        Direction8::DownLeft,
+        Direction8::Left,
+        Direction8::UpLeft,
+    ];
+
+    /// The move of one step in this direction.
+    pub fn offset<T: From<i8>>(self) -> Point2<T> {
+        let (x, y) = match self {
+            Direction8::Up => (0, -1),
+            Direction8::UpRight => (1, -1),
+            Direction8::Right => (1, 0),
+            Direction8::DownRight => (1, 1),
+            Direction8::Down => (0, 1),
+            Direction8::DownLeft => (-1, 1),
+            Direction8::Left => (-1, 0),
+            Direction8::UpLeft => (-1, -1),
+        };
+        return Point2::new(T::from(x), T::from(y));
+    }
+
+    /// An eighth of a turn clockwise.
+    pub fn turn_right(self) -> Self {
+        return Direction8::ALL[(self as usize + 1) % 8];
+    }
+
+    /// An eighth of a turn counterclockwise.
+    pub fn turn_left(self) -> Self {
+        return Direction8::ALL[(self as usize + 7) % 8];
+    }
+
+    pub fn opposite(self) -> Self {
+        return Direction8::ALL[(self as usize + 4) % 8];
+    }
+}
+
+impl From<Direction4> for Direction8 {
+    fn from(direction: Direction4) -> Self {
+        return Direction8::ALL[direction as usize * 2];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_do_vector_arithmetic() {
+        let mut point: Point2<i32> = Point2::new(2, -3) + Point2::new(1, 1);
+        assert_eq!(point, Point2::new(3, -2));
+        point -= Point2::new(3, 0);
+        assert_eq!(point, Point2::new(0, -2));
+        assert_eq!(-point * 3, Point2::new(0, 6));
+        assert_eq!(Point2::<i32>::new(5, -7).signum(), Point2::new(1, -1));
+    }
+
+    #[test]
+    fn should_rotate_clockwise_with_y_downwards() {
+        let up: Point2<i32> = Direction4::Up.offset();
+        assert_eq!(up.rotate_right(), Direction4::Right.offset());
+        assert_eq!(up.rotate_left(), Direction4::Left.offset());
+        assert_eq!(up.rotate_right().rotate_right(), -up);
+        assert_eq!(Direction4::Left.turn_right(), Direction4::Up);
+        assert_eq!(Direction4::Up.turn_left(), Direction4::Left);
+        assert_eq!(Direction4::Down.opposite(), Direction4::Up);
+        assert_eq!(Direction8::UpLeft.turn_right(), Direction8::Up);
+        assert_eq!(Direction8::from(Direction4::Down), Direction8::Down);
+    }
+
+    #[test]
+    fn should_measure_distances() {
+        let a: Point2<i32> = Point2::new(1, 2);
+        let b = Point2::new(4, -2);
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!(a.euclidean(b), 5.0);
+    }
+
+    #[test]
+    fn should_step_in_every_direction() {
+        let around: Vec<Point2<i64>> = Direction8::ALL.iter().map(|d| d.offset()).collect();
+        assert!(around
+            .iter()
+            .all(|offset| offset.chebyshev(Point2::default()) == 1));
+        assert_eq!(
+            around.iter().copied().fold(Point2::default(), Add::add),
+            Point2::new(0, 0)
+        );
+    }
+}
@@ -0,0 +1,253 @@
+use crate::{
+    error::AocError,
+    geometry::{Direction4, Direction8, Point2},
+};
+
+/// `x` is the column and `y` the row, starting from the top left cell.
+pub type Position = Point2<usize>;
+
+/// A rectangle of cells stored row after row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Fails when the number of cells does not fill `width` columns exactly.
+    pub fn new(width: usize, cells: Vec<T>) -> Result<Grid<T>, AocError> {
+        if width == 0 || cells.is_empty() || !cells.len().is_multiple_of(width) {
+            return Err(AocError::validation(format!(
+                "{} cells cannot fill rows of {}",
+                cells.len(),
+                width
+            )));
+        }
+        let height = cells.len() / width;
+        return Ok(Grid {
+            width,
+            height,
+            cells,
+        });
+    }
+
+    /// One row per line, each character being turned into a cell by `cell`,
+    /// which is given the position of the character. A trailing line break is
+    /// ignored.
+    pub fn parse(
+        input: &str,
+        mut cell: impl FnMut(Position, char) -> Result<T, AocError>,
+    ) -> Result<Grid<T>, AocError> {
+        let mut width = None;
+        let mut cells = Vec::new();
+        for (row, line) in input.lines().enumerate() {
+            let before = cells.len();
+            for (column, c) in line.chars().enumerate() {
+                cells.push(cell(Point2::new(column, row), c)?);
+            }
+            let columns = cells.len() - before;
+            match width {
+                Some(width) if width != columns => {
+                    return Err(AocError::validation(format!(
+                        "line {} has {} cells instead of {}",
+                        row + 1,
+                        columns,
+                        width
+                    )));
+                }
+                _ => width = Some(columns),
+            }
+        }
+        if cells.is_empty() {
+            return Err(AocError::validation("the grid is empty"));
+        }
+        return Grid::new(width.unwrap_or(0), cells);
+    }
+
+    pub fn width(&self) -> usize {
+        return self.width;
+    }
+
+    pub fn height(&self) -> usize {
+        return self.height;
+    }
+
+    fn index(&self, position: Position) -> Option<usize> {
+        if position.y < self.height && position.x < self.width {
+            return Some(position.y * self.width + position.x);
+        }
+        return None;
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        return self.index(position).map(|index| &self.cells[index]);
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        return self.index(position).map(|index| &mut self.cells[index]);
+    }
+
+    /// The position `offset` away, when it is on the grid.
+    fn step(&self, position: Position, offset: Point2<isize>) -> Option<Position> {
+        let position = Point2::new(
+            position.x.checked_add_signed(offset.x)?,
+            position.y.checked_add_signed(offset.y)?,
+        );
+        return self.index(position).map(|_| position);
+    }
+
+    /// Every position, row after row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        return (0..self.cells.len()).map(move |index| Point2::new(index % width, index / width));
+    }
+
+    /// Every cell with its position, row after row.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        return self.positions().zip(self.cells.iter());
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        return self.cells.chunks(self.width);
+    }
+
+    /// The positions above, below, left and right of `position` on the grid.
+    pub fn neighbours4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        return Direction4::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(position, direction.offset()));
+    }
+
+    /// The positions around `position` on the grid, diagonals included.
+    pub fn neighbours8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        return Direction8::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(position, direction.offset()));
+    }
+
+    /// The cells from `position`, excluded, to the edge of the grid.
+    pub fn ray(
+        &self,
+        position: Position,
+        direction: Direction4,
+    ) -> impl Iterator<Item = (Position, &T)> + '_ {
+        let offset = direction.offset();
+        return std::iter::successors(self.step(position, offset), move |&current| {
+            self.step(current, offset)
+        })
+        .map(move |current| (current, &self.cells[current.y * self.width + current.x]));
+    }
+
+    pub fn map<U>(&self, mut cell: impl FnMut(&T) -> U) -> Grid<U> {
+        return Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(&mut cell).collect(),
+        };
+    }
+
+    /// One line per row, each cell being shown as the character `cell` returns.
+    pub fn render(&self, mut cell: impl FnMut(&T) -> char) -> String {
+        return self
+            .rows()
+            .map(|row| row.iter().map(&mut cell).collect::<String>())
+            .collect::<Vec<String>>()
+            .join("\n");
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Rows become columns.
+    pub fn transpose(&self) -> Grid<T> {
+        let cells = (0..self.width)
+            .flat_map(|column| {
+                (0..self.height).map(move |row| self.cells[row * self.width + column].clone())
+            })
+            .collect();
+        return Grid {
+            width: self.height,
+            height: self.width,
+            cells,
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(x: usize, y: usize) -> Position {
+        return Point2::new(x, y);
+    }
+
+    fn digits(input: &str) -> Result<Grid<u32>, AocError> {
+        return Grid::parse(input, |position, c| {
+            c.to_digit(10)
+                .ok_or_else(|| AocError::parse(format!("{:?} at {:?} is not a digit", c, position)))
+        });
+    }
+
+    #[test]
+    fn should_parse_and_render() {
+        let grid = digits("123\n456\n").unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get(at(2, 1)), Some(&6));
+        assert_eq!(grid.get(at(0, 2)), None);
+        assert_eq!(grid.get(at(3, 0)), None);
+        assert_eq!(
+            grid.render(|&digit| char::from_digit(digit, 10).unwrap()),
+            "123\n456"
+        );
+    }
+
+    #[test]
+    fn should_reject_invalid_grids() {
+        assert!(matches!(digits("12\n3x"), Err(AocError::Parse(_))));
+        assert!(matches!(digits("12\n345"), Err(AocError::Validation(_))));
+        assert!(matches!(digits(""), Err(AocError::Validation(_))));
+        assert!(Grid::new(2, vec![1, 2, 3]).is_err());
+    }
+
+    #[test]
+    fn should_list_neighbours_on_the_grid() {
+        let grid = digits("123\n456\n789").unwrap();
+        assert_eq!(
+            grid.neighbours4(at(0, 0)).collect::<Vec<_>>(),
+            vec![at(1, 0), at(0, 1)]
+        );
+        assert_eq!(grid.neighbours4(at(1, 1)).count(), 4);
+        assert_eq!(
+            grid.neighbours8(at(2, 0)).collect::<Vec<_>>(),
+            vec![at(2, 1), at(1, 1), at(1, 0)]
+        );
+        assert_eq!(grid.neighbours8(at(1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn should_cast_rays_to_the_edges() {
+        let grid = digits("123\n456\n789").unwrap();
+        let ray = |position, direction| -> Vec<u32> {
+            return grid
+                .ray(position, direction)
+                .map(|(_, &digit)| digit)
+                .collect();
+        };
+        assert_eq!(ray(at(1, 1), Direction4::Up), vec![2]);
+        assert_eq!(ray(at(0, 0), Direction4::Down), vec![4, 7]);
+        assert_eq!(ray(at(2, 2), Direction4::Left), vec![8, 7]);
+        assert_eq!(ray(at(2, 1), Direction4::Right), Vec::<u32>::new());
+    }
+
+    #[test]
+    fn should_transpose() {
+        let grid = digits("123\n456").unwrap();
+        let transposed = grid.transpose();
+        assert_eq!((transposed.width(), transposed.height()), (2, 3));
+        assert_eq!(
+            transposed.rows().collect::<Vec<_>>(),
+            vec![[1, 4], [2, 5], [3, 6]]
+        );
+        assert_eq!(transposed.transpose(), grid);
+    }
+}
@@ -0,0 +1,347 @@
+use std::{
+    env, fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+use crate::error::AocError;
+
+/// Directory searched before the `resources/` convention.
+pub const INPUT_DIR_VARIABLE: &str = "AOC_INPUT_DIR";
+
+const RESOURCES_DIR: &str = "resources";
+
+/// `day7.txt` for the puzzle input, `day10-example.txt` for the `example` variant.
+pub fn file_name(day: u8, variant: Option<&str>) -> String {
+    return match variant {
+        Some(variant) => format!("day{}-{}.txt", day, variant),
+        None => format!("day{}.txt", day),
+    };
+}
+
+/// `2022/day7.txt`: the inputs of each year live in their own directory.
+pub fn relative_path(year: u16, day: u8, variant: Option<&str>) -> PathBuf {
+    return Path::new(&year.to_string()).join(file_name(day, variant));
+}
+
+/// A profile names the inputs of another account, such as `alice`. It cannot
+/// be confused with a year, so it is not only digits.
+pub fn is_profile_name(name: &str) -> bool {
+    return !name.is_empty()
+        && !name.chars().all(|c| c.is_ascii_digit())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+}
+
+/// Finds the input of a day, in order: an explicit path or stdin, the
+/// `AOC_INPUT_DIR` directory, and finally the `resources/` directory. Stdin is
+/// only read when asked for, never as a fallback between the directories.
+/// Both directories hold one subdirectory per year, and one per profile
+/// holding the years of that profile.
+#[derive(Debug, Clone)]
+pub struct InputProvider {
+    path: Option<PathBuf>,
+    input_dir: Option<PathBuf>,
+    stdin: bool,
+    resources_dir: PathBuf,
+    /// `None` for the inputs of the account the tools run for.
+    profile: Option<String>,
+}
+
+impl Default for InputProvider {
+    fn default() -> Self {
+        return InputProvider {
+            path: None,
+            input_dir: env::var_os(INPUT_DIR_VARIABLE).map(PathBuf::from),
+            stdin: false,
+            resources_dir: PathBuf::from(RESOURCES_DIR),
+            profile: None,
+        };
+    }
+}
+
+impl InputProvider {
+    pub fn new() -> Self {
+        return InputProvider::default();
+    }
+
+    pub fn with_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.path = Some(path.into());
+        return self;
+    }
+
+    pub fn with_input_dir(mut self, input_dir: impl Into<PathBuf>) -> Self {
+        self.input_dir = Some(input_dir.into());
+        return self;
+    }
+
+    pub fn with_resources_dir(mut self, resources_dir: impl Into<PathBuf>) -> Self {
+        self.resources_dir = resources_dir.into();
+        return self;
+    }
+
+    /// Reads stdin instead of any file. It is only read when asked for, as a
+    /// pipe left open without being written to would block forever.
+    pub fn with_stdin(mut self, stdin: bool) -> Self {
+        self.stdin = stdin;
+        return self;
+    }
+
+    pub fn with_profile(mut self, profile: Option<String>) -> Self {
+        self.profile = profile;
+        return self;
+    }
+
+    pub fn profile(&self) -> Option<&str> {
+        return self.profile.as_deref();
+    }
+
+    /// Where downloaded inputs are stored: the `AOC_INPUT_DIR` directory when
+    /// set, the `resources/` directory otherwise, in the directory of the
+    /// profile.
+    pub fn cache_dir(&self) -> PathBuf {
+        let dir = self.input_dir.as_deref().unwrap_or(&self.resources_dir);
+        return match &self.profile {
+            Some(profile) => dir.join(profile),
+            None => dir.to_path_buf(),
+        };
+    }
+
+    /// The profiles with a directory in the `AOC_INPUT_DIR` directory or in
+    /// the `resources/` directory, sorted.
+    pub fn profiles(&self) -> Vec<String> {
+        let mut profiles: Vec<String> = self
+            .input_dir
+            .iter()
+            .chain([&self.resources_dir])
+            .filter_map(|dir| fs::read_dir(dir).ok())
+            .flatten()
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.path().is_dir())
+            .filter_map(|entry| entry.file_name().into_string().ok())
+            .filter(|name| is_profile_name(name))
+            .collect();
+        profiles.sort();
+        profiles.dedup();
+        return profiles;
+    }
+
+    /// `2022/day7.txt`, in the directory of the profile when there is one.
+    fn relative_path(&self, year: u16, day: u8, variant: Option<&str>) -> PathBuf {
+        return match &self.profile {
+            Some(profile) => Path::new(profile).join(relative_path(year, day, variant)),
+            None => relative_path(year, day, variant),
+        };
+    }
+
+    /// The file the input of a day would be read from in the `AOC_INPUT_DIR`
+    /// directory or the `resources/` directory, when there is one.
+    pub fn locate(&self, year: u16, day: u8, variant: Option<&str>) -> Option<PathBuf> {
+        let name = self.relative_path(year, day, variant);
+        return self
+            .input_dir
+            .iter()
+            .chain([&self.resources_dir])
+            .map(|dir| dir.join(&name))
+            .find(|path| path.is_file());
+    }
+
+    /// Where the input of a day is read from, for messages.
+    pub fn describe(&self, year: u16, day: u8) -> String {
+        if let Some(path) = &self.path {
+            return path.display().to_string();
+        }
+        if self.stdin {
+            return "stdin".to_string();
+        }
+        let path = self
+            .locate(year, day, None)
+            .unwrap_or_else(|| self.relative_path(year, day, None));
+        return path.display().to_string();
+    }
+
+    pub fn load(&self, year: u16, day: u8) -> Result<String, AocError> {
+        return self.load_variant(year, day, None);
+    }
+
+    pub fn load_variant(
+        &self,
+        year: u16,
+        day: u8,
+        variant: Option<&str>,
+    ) -> Result<String, AocError> {
+        if let Some(path) = &self.path {
+            return read(path);
+        }
+        if self.stdin {
+            let mut input = String::new();
+            io::stdin()
+                .read_to_string(&mut input)
+                .map_err(|e| AocError::Io(format!("unable to read stdin: {}", e)))?;
+            return Ok(input);
+        }
+
+        let name = self.relative_path(year, day, variant);
+        let mut searched = Vec::new();
+
+        if let Some(input_dir) = &self.input_dir {
+            let path = input_dir.join(&name);
+            if path.is_file() {
+                return read(&path);
+            }
+            searched.push(path);
+        }
+
+        let path = self.resources_dir.join(&name);
+        if path.is_file() {
+            return read(&path);
+        }
+        searched.push(path);
+
+        return Err(AocError::MissingInput {
+            year,
+            day,
+            searched,
+        });
+    }
+}
+
+fn read(path: &Path) -> Result<String, AocError> {
+    return fs::read_to_string(path)
+        .map_err(|e| AocError::Io(format!("unable to read {}: {}", path.display(), e)));
+}
+
+/// The puzzle input of a day, as found by the default [`InputProvider`].
+pub fn read_input(year: u16, day: u8) -> Result<String, AocError> {
+    return InputProvider::new().load(year, day);
+}
+
+/// A named input of a day, such as `2022/day10-example.txt`.
+pub fn read_variant(year: u16, day: u8, variant: &str) -> Result<String, AocError> {
+    return InputProvider::new().load_variant(year, day, Some(variant));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-input-{}-{}", name, std::process::id()));
+        fs::create_dir_all(dir.join("2022")).unwrap();
+        return dir;
+    }
+
+    #[test]
+    fn should_name_input_files() {
+        assert_eq!(file_name(7, None), "day7.txt");
+        assert_eq!(file_name(10, Some("example")), "day10-example.txt");
+        assert_eq!(
+            relative_path(2022, 10, Some("example")),
+            Path::new("2022/day10-example.txt")
+        );
+    }
+
+    #[test]
+    fn should_prefer_the_explicit_path() {
+        let dir = temp_dir("explicit");
+        fs::write(dir.join("custom.txt"), "explicit").unwrap();
+        fs::write(dir.join("2022/day7.txt"), "from dir").unwrap();
+        let provider = InputProvider::new()
+            .with_input_dir(&dir)
+            .with_path(dir.join("custom.txt"));
+        assert_eq!(provider.load(2022, 7), Ok("explicit".to_string()));
+    }
+
+    #[test]
+    fn should_prefer_the_input_dir_over_resources() {
+        let dir = temp_dir("input-dir");
+        fs::write(dir.join("2022/day7.txt"), "from dir").unwrap();
+        let provider = InputProvider::new().with_input_dir(&dir);
+        assert_eq!(provider.load(2022, 7), Ok("from dir".to_string()));
+        assert!(provider.load(2021, 7).is_err());
+    }
+
+    #[test]
+    fn should_fall_back_to_resources() {
+        let dir = temp_dir("fallback");
+        let provider = InputProvider::new().with_input_dir(&dir);
+        assert_eq!(
+            provider.load_variant(2022, 10, Some("example")),
+            fs::read_to_string("resources/2022/day10-example.txt")
+                .map_err(|e| AocError::Io(e.to_string()))
+        );
+    }
+
+    #[test]
+    fn should_report_where_it_looked() {
+        let dir = temp_dir("missing");
+        let resources = temp_dir("missing-resources");
+        let provider = InputProvider::new()
+            .with_input_dir(&dir)
+            .with_resources_dir(&resources);
+        assert_eq!(
+            provider.load(2022, 4),
+            Err(AocError::MissingInput {
+                year: 2022,
+                day: 4,
+                searched: vec![dir.join("2022/day4.txt"), resources.join("2022/day4.txt")],
+            })
+        );
+    }
+
+    #[test]
+    fn should_describe_where_an_input_is_read_from() {
+        let dir = temp_dir("describe");
+        let resources = temp_dir("describe-resources");
+        fs::write(resources.join("2022/day7.txt"), "7").unwrap();
+        let provider = InputProvider::new()
+            .with_input_dir(&dir)
+            .with_resources_dir(&resources);
+        assert_eq!(
+            provider.describe(2022, 7),
+            resources.join("2022/day7.txt").display().to_string()
+        );
+        assert_eq!(provider.describe(2022, 8), "2022/day8.txt");
+        assert_eq!(provider.clone().with_stdin(true).describe(2022, 7), "stdin");
+        assert_eq!(
+            provider.with_path("custom.txt").describe(2022, 7),
+            "custom.txt"
+        );
+    }
+
+    #[test]
+    fn should_read_the_inputs_of_a_profile() {
+        let dir = temp_dir("profile");
+        fs::create_dir_all(dir.join("alice/2022")).unwrap();
+        fs::create_dir_all(dir.join("bob")).unwrap();
+        fs::write(dir.join("2022/day7.txt"), "mine").unwrap();
+        fs::write(dir.join("alice/2022/day7.txt"), "alice's").unwrap();
+        let provider = InputProvider::new().with_resources_dir(&dir);
+        assert_eq!(provider.profiles(), vec!["alice", "bob"]);
+
+        let alice = provider.clone().with_profile(Some("alice".to_string()));
+        assert_eq!(alice.load(2022, 7), Ok("alice's".to_string()));
+        assert_eq!(alice.cache_dir(), dir.join("alice"));
+        assert_eq!(provider.load(2022, 7), Ok("mine".to_string()));
+        assert!(provider
+            .with_profile(Some("bob".to_string()))
+            .load(2022, 7)
+            .is_err());
+    }
+
+    #[test]
+    fn should_tell_profiles_from_years() {
+        assert!(is_profile_name("alice"));
+        assert!(is_profile_name("team-b_2"));
+        assert!(!is_profile_name("2022"));
+        assert!(!is_profile_name(""));
+        assert!(!is_profile_name("../alice"));
+    }
+
+    #[test]
+    fn should_fail_on_a_missing_explicit_path() {
+        let provider = InputProvider::new().with_path("does/not/exist.txt");
+        assert!(matches!(provider.load(2022, 7), Err(AocError::Io(_))));
+    }
+}
@@ -0,0 +1,47 @@
+//! Advent of Code solutions, one `yYYYY` module per year.
+//!
+//! Every day lives in its own `yYYYY::day_N` module, with its input model, a
+//! `parse` function and `part1`/`part2` working on the parsed input. The
+//! [`registry`] lists the days of every year behind the object safe
+//! [`solution::Puzzle`] trait so tools can run them without knowing their
+//! input types. The other modules are shared by all the years.
+//!
+//! ```
+//! use aoc::y2022::day_10::{interpret_instructions, parse, Instruction};
+//!
+//! let instructions = parse("noop\naddx 3\naddx -5").unwrap();
+//! assert_eq!(instructions[1], Instruction::Addx(3));
+//! let register = interpret_instructions(&instructions);
+//! assert_eq!(register[&3], 1);
+//! assert_eq!(register[&5], 4);
+//! ```
+#![allow(non_snake_case, clippy::needless_return)]
+
+pub mod answers;
+pub mod bench;
+pub mod differential;
+pub mod error;
+pub mod generate;
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod memory;
+pub mod normalize;
+pub mod parsing;
+pub mod registry;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+pub mod solution;
+pub mod status;
+pub mod submit;
+pub mod table;
+pub mod trace;
+pub mod verify;
+pub mod website;
+pub mod y2022;
+
+#[cfg(test)]
+mod fixtures;
+#[cfg(test)]
+mod http_stub;
@@ -1,12 +1,405 @@
-mod day_1;
-mod day_10;
-mod day_2;
-mod day_3;
-mod day_5;
-mod day_6;
-mod day_7;
-mod day_8;
-mod day_9;
-// pub use crate::advent_of_code::dayone;
-
-fn main() {}
+#![allow(clippy::needless_return)]
+
+mod cli;
+
+use std::{
+    env,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::{Instant, SystemTime, UNIX_EPOCH},
+};
+
+use aoc::{
+    answers::{self, Answers},
+    bench::{self, Baseline},
+    error::AocError,
+    input::InputProvider,
+    registry,
+    report::{self, Format},
+    runner::{self, RunResult},
+    scaffold,
+    solution::{Answer, Part, Puzzle},
+    status,
+    submit::{self, SubmissionLog, Verdict},
+    trace, verify,
+    website::{self, Fetched},
+};
+use cli::{BenchOptions, Command, Selection};
+
+/// Parse errors pointing into the input file, shown once per day as a
+/// compiler would.
+fn print_diagnostics(results: &[RunResult], inputs: &InputProvider) {
+    let mut diagnosed = None;
+    for result in results {
+        if let Err(AocError::Parse(error)) = &result.answer {
+            let day = (result.year, result.day);
+            if error.location.is_some() && diagnosed != Some(day) {
+                let origin = format!(
+                    "day {} of {} in {}",
+                    result.day,
+                    result.year,
+                    inputs.describe(result.year, result.day)
+                );
+                eprintln!("{}", error.diagnostic(&origin));
+                diagnosed = Some(day);
+            }
+        }
+    }
+}
+
+fn print_text(results: &[RunResult], inputs: &InputProvider) {
+    for result in results {
+        match &result.answer {
+            Ok(answer) => match &result.solve_memory {
+                Some(memory) => println!(
+                    "Day {} part {}: {} ({})",
+                    result.day,
+                    result.part,
+                    answer,
+                    report::format_memory(memory)
+                ),
+                None => println!("Day {} part {}: {}", result.day, result.part, answer),
+            },
+            Err(AocError::NotImplemented { .. }) => {
+                eprintln!("Day {} part {}: not implemented", result.day, result.part)
+            }
+            Err(AocError::Parse(error)) if error.location.is_some() => {}
+            Err(error) => eprintln!("Day {} part {}: {}", result.day, result.part, error),
+        }
+    }
+    print_diagnostics(results, inputs);
+}
+
+/// Unsolved parts only count as a failure when they were explicitly requested.
+fn report(results: &[RunResult], inputs: &InputProvider, format: Format, explicit: bool) -> bool {
+    match format {
+        Format::Text => print_text(results, inputs),
+        Format::Json => println!("{}", report::render_json(results)),
+        Format::Csv => println!("{}", report::render_csv(results)),
+    }
+    return !results.iter().any(|result| result.is_failure(explicit));
+}
+
+fn load_answers(answers: Option<PathBuf>) -> Option<Answers> {
+    let path = answers.unwrap_or_else(|| PathBuf::from(answers::ANSWERS_FILE));
+    return match Answers::load(&path) {
+        Ok(answers) => Some(answers),
+        Err(error) => {
+            eprintln!("{}: {}", path.display(), error);
+            None
+        }
+    };
+}
+
+/// The registered days of `year`, reporting a year without any.
+fn days_of(year: u16) -> Option<&'static [&'static dyn Puzzle]> {
+    let days = registry::days(year);
+    if days.is_none() {
+        eprintln!("Year {} is not available", year);
+    }
+    return days;
+}
+
+/// The registered day, reporting a day that is not.
+fn find(year: u16, day: u8) -> Option<&'static dyn Puzzle> {
+    let puzzle = registry::find(year, day);
+    if puzzle.is_none() {
+        eprintln!("Day {} of {} is not available", day, year);
+    }
+    return puzzle;
+}
+
+fn verify_all(year: u16, answers: Option<PathBuf>) -> bool {
+    let (days, answers) = match (days_of(year), load_answers(answers)) {
+        (Some(days), Some(answers)) => (days, answers),
+        _ => return false,
+    };
+
+    let verifications = verify::verify(days, &InputProvider::new(), &answers);
+    println!("{}", verify::render(&verifications));
+    return verifications
+        .iter()
+        .all(|verification| verification.status() != verify::Status::Fail);
+}
+
+/// Only fails when the answers cannot be loaded: the calendar shows the rest.
+fn print_status(year: u16, answers: Option<PathBuf>) -> bool {
+    let (days, answers) = match (days_of(year), load_answers(answers)) {
+        (Some(days), Some(answers)) => (days, answers),
+        _ => return false,
+    };
+
+    let verifications = verify::verify(days, &InputProvider::new(), &answers);
+    println!(
+        "Advent of Code {}\n\n{}",
+        year,
+        status::render(&verifications)
+    );
+    return true;
+}
+
+/// Fails when a day cannot be timed or a median regressed past the threshold.
+fn bench_days(options: BenchOptions) -> bool {
+    let puzzles: Vec<&'static dyn Puzzle> = match options.day {
+        Some(day) => match find(options.year, day) {
+            Some(puzzle) => vec![puzzle],
+            None => return false,
+        },
+        None => match days_of(options.year) {
+            Some(days) => days.to_vec(),
+            None => return false,
+        },
+    };
+
+    let baseline = match &options.baseline {
+        Some(path) => match Baseline::load(path) {
+            Ok(baseline) => Some(baseline),
+            Err(error) => {
+                eprintln!("{}: {}", path.display(), error);
+                return false;
+            }
+        },
+        None => None,
+    };
+
+    let inputs = InputProvider::new();
+    let mut success = true;
+    let mut measurements = Vec::new();
+    for puzzle in puzzles {
+        let timed = inputs.load(puzzle.year(), puzzle.day()).and_then(|input| {
+            bench::bench_puzzle(puzzle, &input, options.iterations, options.timeout)
+        });
+        match timed {
+            Ok(timed) => measurements.extend(timed),
+            Err(error) => {
+                eprintln!("Day {}: {}", puzzle.day(), error);
+                success = false;
+            }
+        }
+    }
+
+    println!(
+        "{}",
+        bench::render(&measurements, baseline.as_ref(), options.threshold)
+    );
+
+    if let Some(baseline) = &baseline {
+        let regressions = measurements
+            .iter()
+            .filter_map(|measurement| bench::compare(measurement, baseline, options.threshold))
+            .filter(|comparison| comparison.regression)
+            .count();
+        if regressions > 0 {
+            eprintln!("{} regression(s) above {}%", regressions, options.threshold);
+            success = false;
+        }
+    }
+
+    if let Some(path) = &options.save_baseline {
+        if let Err(error) = Baseline::save(path, &measurements) {
+            eprintln!("{}", error);
+            success = false;
+        }
+    }
+    return success;
+}
+
+fn fetch_day(year: u16, day: u8, base_url: Option<String>, profile: Option<String>) -> bool {
+    let client = website::Client::from_env(base_url);
+    let inputs = InputProvider::new().with_profile(profile);
+    return match website::fetch_input(&client, &inputs, year, day) {
+        Ok(Fetched::Cached(path)) => {
+            println!("Day {}: already cached in {}", day, path.display());
+            true
+        }
+        Ok(Fetched::Downloaded(path)) => {
+            println!("Day {}: saved to {}", day, path.display());
+            true
+        }
+        Err(error) => {
+            eprintln!("Day {}: {}", day, error);
+            false
+        }
+    };
+}
+
+/// Guards the part like `run` does, so a panic or a hang is never submitted.
+fn solve(year: u16, day: u8, part: Part) -> Result<Answer, AocError> {
+    let puzzle = registry::find(year, day)
+        .ok_or_else(|| AocError::validation(format!("day {} of {} is not available", day, year)))?;
+    let input = InputProvider::new().load(year, day)?;
+    return runner::guarded(runner::DEFAULT_TIMEOUT, move || puzzle.run(part, &input))?.value;
+}
+
+/// Succeeds only when the website accepts the answer.
+fn submit_answer(year: u16, day: u8, part: Part, base_url: Option<String>) -> bool {
+    let answer = match solve(year, day, part) {
+        Ok(answer) => answer.to_string(),
+        Err(error) => {
+            eprintln!("Day {} part {}: {}", day, part, error);
+            return false;
+        }
+    };
+
+    let path = PathBuf::from(submit::SUBMISSIONS_FILE);
+    let mut log = match SubmissionLog::load(&path) {
+        Ok(log) => log,
+        Err(error) => {
+            eprintln!("{}: {}", path.display(), error);
+            return false;
+        }
+    };
+
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs());
+    let client = website::Client::from_env(base_url);
+    let verdict = submit::submit(&client, &mut log, year, day, part, &answer, now);
+    if let Err(error) = log.save(&path) {
+        eprintln!("{}", error);
+    }
+    return match verdict {
+        Ok(verdict) => {
+            println!("Day {} part {}: {} is {}", day, part, answer, verdict);
+            verdict == Verdict::Correct
+        }
+        Err(error) => {
+            eprintln!("Day {} part {}: {}", day, part, error);
+            false
+        }
+    };
+}
+
+fn new_day(year: u16, day: u8) -> bool {
+    return match scaffold::new_day(Path::new("."), year, day) {
+        Ok(scaffold) => {
+            println!("Created {}", scaffold.module.display());
+            if let Some(year_module) = &scaffold.year_module {
+                println!("Created {}", year_module.display());
+                println!("Registered {} in src/lib.rs and src/registry.rs", year);
+            }
+            for resource in scaffold.resources {
+                println!("Created {}", resource.display());
+            }
+            println!("Registered day {} in src/y{}/mod.rs", day, year);
+            true
+        }
+        Err(error) => {
+            eprintln!("Day {}: {}", day, error);
+            false
+        }
+    };
+}
+
+/// The input goes to stdout, so that it can be redirected to a file.
+fn generate_input(year: u16, day: u8, seed: u64, size: usize) -> bool {
+    let generated = match registry::find(year, day).and_then(|puzzle| puzzle.generate(seed, size)) {
+        Some(generated) => generated,
+        None => {
+            eprintln!("Day {} of {} has no generator", day, year);
+            return false;
+        }
+    };
+    println!("{}", generated.input);
+    for part in Part::ALL {
+        if let Some(answer) = generated.answer(part) {
+            eprintln!("Day {} part {}: {}", day, part, answer);
+        }
+    }
+    return true;
+}
+
+fn main() -> ExitCode {
+    let command = match cli::parse_args(env::args().skip(1)) {
+        Ok(command) => command,
+        Err(error) => {
+            eprintln!("{}\n\n{}", error, cli::USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    trace::init_from_env();
+    if let Command::Run { trace: true, .. } = command {
+        trace::set_level(Some(trace::Level::Debug));
+    }
+
+    let success = match command {
+        Command::Help => {
+            println!("{}", cli::USAGE);
+            true
+        }
+        Command::Run {
+            year,
+            selection: Selection::Day { day, part, input },
+            format,
+            timeout,
+            profile,
+            ..
+        } => match find(year, day) {
+            Some(puzzle) => {
+                let parts = part.map(|p| vec![p]).unwrap_or_else(|| Part::ALL.to_vec());
+                let inputs = match input {
+                    Some(path) if path == Path::new(cli::STDIN) => {
+                        InputProvider::new().with_stdin(true)
+                    }
+                    Some(path) => InputProvider::new().with_path(path),
+                    None => InputProvider::new().with_profile(profile),
+                };
+                let results = runner::run_puzzle(puzzle, &parts, &inputs, timeout);
+                report(&results, &inputs, format, part.is_some())
+            }
+            None => false,
+        },
+        Command::Run {
+            year,
+            selection: Selection::All { jobs },
+            format,
+            timeout,
+            profile,
+            ..
+        } => match days_of(year) {
+            Some(days) => {
+                let start = Instant::now();
+                let inputs = InputProvider::new().with_profile(profile);
+                let results = runner::run_all(days, &inputs, jobs, timeout);
+                match format {
+                    Format::Text => {
+                        println!("{}", report::render_summary(&results, start.elapsed()));
+                        print_diagnostics(&results, &inputs);
+                        !results.iter().any(|result| result.is_failure(false))
+                    }
+                    format => report(&results, &inputs, format, false),
+                }
+            }
+            None => false,
+        },
+        Command::Verify { year, answers } => verify_all(year, answers),
+        Command::Status { year, answers } => print_status(year, answers),
+        Command::Bench(options) => bench_days(options),
+        Command::Fetch {
+            year,
+            day,
+            base_url,
+            profile,
+        } => fetch_day(year, day, base_url, profile),
+        Command::Submit {
+            year,
+            day,
+            part,
+            base_url,
+        } => submit_answer(year, day, part, base_url),
+        Command::NewDay { year, day } => new_day(year, day),
+        Command::Generate {
+            year,
+            day,
+            seed,
+            size,
+        } => generate_input(year, day, seed, size),
+    };
+
+    return if success {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    };
+}
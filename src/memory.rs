@@ -0,0 +1,166 @@
+//! Allocation counting, enabled by building with `--features count-allocations`.
+//!
+//! The counters are kept per thread, so the runner measures each step on the
+//! thread it spawns for it, whatever runs on the other threads meanwhile.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+};
+
+use serde::Serialize;
+
+/// Allocations made while running one step.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct Memory {
+    pub allocations: u64,
+    /// Total of the allocated sizes, memory freed during the step included.
+    pub allocated: u64,
+    /// Most bytes allocated by the step and not yet freed at the same time.
+    pub peak: u64,
+}
+
+#[derive(Clone, Copy)]
+struct Counters {
+    allocations: u64,
+    allocated: u64,
+    /// Negative when the step frees memory allocated before it started.
+    live: i64,
+    peak: i64,
+}
+
+impl Counters {
+    const EMPTY: Counters = Counters {
+        allocations: 0,
+        allocated: 0,
+        live: 0,
+        peak: 0,
+    };
+}
+
+thread_local! {
+    static COUNTERS: Cell<Counters> = const { Cell::new(Counters::EMPTY) };
+}
+
+fn record_alloc(size: usize) {
+    let _ = COUNTERS.try_with(|counters| {
+        let mut current = counters.get();
+        current.allocations += 1;
+        current.allocated += size as u64;
+        current.live += size as i64;
+        current.peak = current.peak.max(current.live);
+        counters.set(current);
+    });
+}
+
+fn record_dealloc(size: usize) {
+    let _ = COUNTERS.try_with(|counters| {
+        let mut current = counters.get();
+        current.live -= size as i64;
+        counters.set(current);
+    });
+}
+
+/// The system allocator, counting what the current thread allocates.
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        return ptr;
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        return ptr;
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record_dealloc(layout.size());
+    }
+
+    /// Counted as a new allocation replacing the old one, as growing a
+    /// collection usually copies it.
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            record_dealloc(layout.size());
+            record_alloc(new_size);
+        }
+        return new_ptr;
+    }
+}
+
+#[cfg(feature = "count-allocations")]
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Whether the counting allocator is installed.
+pub fn enabled() -> bool {
+    return cfg!(feature = "count-allocations");
+}
+
+fn count<T>(step: impl FnOnce() -> T) -> (T, Memory) {
+    COUNTERS.with(|counters| counters.set(Counters::EMPTY));
+    let value = step();
+    let counters = COUNTERS.with(|counters| counters.get());
+    let memory = Memory {
+        allocations: counters.allocations,
+        allocated: counters.allocated,
+        peak: counters.peak.max(0) as u64,
+    };
+    return (value, memory);
+}
+
+/// Runs `step` and returns what it allocated on the current thread, or `None`
+/// when the counting allocator is not installed.
+pub fn measure<T>(step: impl FnOnce() -> T) -> (T, Option<Memory>) {
+    if !enabled() {
+        return (step(), None);
+    }
+    let (value, memory) = count(step);
+    return (value, Some(memory));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(not(feature = "count-allocations"))]
+    #[test]
+    fn should_track_the_peak_of_live_bytes() {
+        let ((), memory) = count(|| {
+            record_alloc(100);
+            record_alloc(50);
+            record_dealloc(100);
+            record_alloc(20);
+            record_dealloc(30);
+        });
+        assert_eq!(
+            memory,
+            Memory {
+                allocations: 3,
+                allocated: 170,
+                peak: 150
+            }
+        );
+        assert_eq!(measure(|| 1), (1, None));
+    }
+
+    #[cfg(feature = "count-allocations")]
+    #[test]
+    fn should_count_the_allocations_of_the_step() {
+        let (_, memory) = measure(|| vec![0u8; 1000]);
+        let memory = memory.unwrap();
+        assert!(memory.allocations >= 1);
+        assert!(memory.allocated >= 1000);
+        assert!(memory.peak >= 1000);
+    }
+}
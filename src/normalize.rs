@@ -0,0 +1,157 @@
+use crate::error::{AocError, Location, ParseError};
+
+/// Options of [`normalize`], chosen per day through [`crate::solution::Solution::DEDENT`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Normalization {
+    pub dedent: bool,
+}
+
+fn indentation(line: &str) -> usize {
+    return line.len() - line.trim_start_matches([' ', '\t']).len();
+}
+
+/// Removes the indentation shared by every non blank line. A first line
+/// without indentation is left out, as in `"A Y\n    B X"` test literals.
+fn dedent(lines: Vec<&str>) -> Vec<&str> {
+    let skip_first = lines.first().is_some_and(|first| indentation(first) == 0);
+    let common = lines
+        .iter()
+        .skip(if skip_first { 1 } else { 0 })
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| indentation(line))
+        .min()
+        .unwrap_or(0);
+
+    return lines
+        .into_iter()
+        .enumerate()
+        .map(|(idx, line)| {
+            if (idx == 0 && skip_first) || line.trim().is_empty() {
+                line.trim_start_matches([' ', '\t'])
+            } else {
+                &line[common..]
+            }
+        })
+        .collect();
+}
+
+/// Converts CRLF line endings to LF, strips the trailing blank lines and,
+/// when asked, removes the common indentation.
+pub fn normalize(input: &str, options: Normalization) -> String {
+    let mut lines: Vec<&str> = input
+        .split('\n')
+        .map(|line| line.strip_suffix('\r').unwrap_or(line))
+        .collect();
+
+    while lines.last().is_some_and(|line| line.trim().is_empty()) {
+        lines.pop();
+    }
+
+    if options.dedent {
+        lines = dedent(lines);
+    }
+
+    return lines.join("\n");
+}
+
+/// Moves the location of a parse error in the normalized input to the same
+/// place in `original`, as normalizing keeps the lines where they are and only
+/// removes indentation and line endings around them.
+pub fn restore_location(error: AocError, original: &str) -> AocError {
+    let (message, location) = match error {
+        AocError::Parse(ParseError {
+            message,
+            location: Some(location),
+        }) => (message, location),
+        error => return error,
+    };
+    let text = original
+        .split('\n')
+        .nth(location.line - 1)
+        .map(|line| line.strip_suffix('\r').unwrap_or(line));
+    let location = match text {
+        Some(text) => Location {
+            column: location.column
+                + text
+                    .chars()
+                    .count()
+                    .saturating_sub(location.text.chars().count()),
+            line: location.line,
+            text: text.to_string(),
+        },
+        None => location,
+    };
+    return AocError::Parse(ParseError {
+        message,
+        location: Some(location),
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEEP_INDENT: Normalization = Normalization { dedent: false };
+    const DEDENT: Normalization = Normalization { dedent: true };
+
+    #[test]
+    fn should_convert_crlf_to_lf() {
+        assert_eq!(normalize("1\r\n2\r\n\r\n3", KEEP_INDENT), "1\n2\n\n3");
+    }
+
+    #[test]
+    fn should_strip_trailing_blank_lines() {
+        assert_eq!(normalize("1\n2\n", KEEP_INDENT), "1\n2");
+        assert_eq!(normalize("1\n2\n\n  \n", KEEP_INDENT), "1\n2");
+        assert_eq!(normalize("\n\n", KEEP_INDENT), "");
+    }
+
+    #[test]
+    fn should_keep_leading_spaces_without_dedent() {
+        let input = "    [D]    \n[N] [C]    \n";
+        assert_eq!(normalize(input, KEEP_INDENT), "    [D]    \n[N] [C]    ");
+    }
+
+    #[test]
+    fn should_dedent_indented_blocks() {
+        assert_eq!(
+            normalize("    A Y\n      B X\n    C Z", DEDENT),
+            "A Y\n  B X\nC Z"
+        );
+    }
+
+    #[test]
+    fn should_locate_errors_in_the_original_input() {
+        let original = "    1\r\n    2x\r\n\r\n";
+        let normalized = normalize(original, DEDENT);
+        let error = AocError::Parse(ParseError {
+            message: "expected a digit".to_string(),
+            location: Some(Location {
+                line: 2,
+                column: 2,
+                text: normalized.lines().nth(1).unwrap().to_string(),
+            }),
+        });
+        assert_eq!(
+            restore_location(error, original),
+            AocError::Parse(ParseError {
+                message: "expected a digit".to_string(),
+                location: Some(Location {
+                    line: 2,
+                    column: 6,
+                    text: "    2x".to_string(),
+                }),
+            })
+        );
+    }
+
+    #[test]
+    fn should_dedent_test_literals() {
+        let input = "A Y
+        B X
+
+        C Z
+        ";
+        assert_eq!(normalize(input, DEDENT), "A Y\nB X\n\nC Z");
+    }
+}
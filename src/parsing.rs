@@ -0,0 +1,406 @@
+//! nom combinators shared by the days. Their errors keep the input left where
+//! parsing failed, so that [`parse_all`] can tell the line and the column.
+
+use std::{fmt, str::FromStr};
+
+use nom::{
+    character::complete::{digit1, one_of, satisfy, space0, space1},
+    combinator::{map_res, opt, recognize},
+    error::{context, ContextError, ErrorKind, FromExternalError},
+    multi::many0,
+    sequence::pair,
+    IResult,
+};
+
+use crate::{
+    error::{AocError, Location, ParseError},
+    grid::Grid,
+};
+
+/// Why a parser stopped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error<'a> {
+    /// The input left where parsing failed.
+    pub rest: &'a str,
+    /// What would have been accepted there.
+    pub expected: Option<String>,
+    /// Why the text found there was rejected, such as a number overflowing.
+    pub reason: Option<String>,
+}
+
+pub type PResult<'a, T> = IResult<&'a str, T, Error<'a>>;
+
+impl<'a> Error<'a> {
+    pub fn expected(rest: &'a str, expected: impl Into<String>) -> Self {
+        return Error {
+            rest,
+            expected: Some(expected.into()),
+            reason: None,
+        };
+    }
+
+    /// The error with its message and its position in `input`, which `rest`
+    /// is the end of.
+    pub fn locate(&self, input: &str) -> ParseError {
+        let message = match (&self.expected, &self.reason) {
+            (Some(expected), Some(reason)) => format!("expected {}: {}", expected, reason),
+            (Some(expected), None) => format!("expected {}, found {}", expected, found(self.rest)),
+            (None, Some(reason)) => reason.clone(),
+            (None, None) => format!("unexpected {}", found(self.rest)),
+        };
+        return ParseError {
+            message,
+            location: Some(location(input, input.len() - self.rest.len())),
+        };
+    }
+}
+
+/// Describes what starts `rest`.
+fn found(rest: &str) -> String {
+    return if rest.is_empty() {
+        "end of input".to_string()
+    } else if rest.starts_with("\n\n") {
+        "a blank line".to_string()
+    } else if rest.starts_with('\n') {
+        "end of line".to_string()
+    } else {
+        format!("{:?}", rest.lines().next().unwrap_or_default())
+    };
+}
+
+fn location(input: &str, offset: usize) -> Location {
+    let before = &input[..offset];
+    let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+    return Location {
+        line: before.matches('\n').count() + 1,
+        column: before[line_start..].chars().count() + 1,
+        text: input[line_start..]
+            .lines()
+            .next()
+            .unwrap_or_default()
+            .to_string(),
+    };
+}
+
+fn describe(kind: ErrorKind) -> Option<&'static str> {
+    return match kind {
+        ErrorKind::Digit => Some("a digit"),
+        ErrorKind::Alpha => Some("a letter"),
+        ErrorKind::Space | ErrorKind::MultiSpace => Some("a space"),
+        ErrorKind::CrLf => Some("end of line"),
+        _ => None,
+    };
+}
+
+impl<'a> nom::error::ParseError<&'a str> for Error<'a> {
+    fn from_error_kind(input: &'a str, kind: ErrorKind) -> Self {
+        return Error {
+            rest: input,
+            expected: describe(kind).map(String::from),
+            reason: None,
+        };
+    }
+
+    fn append(_: &'a str, _: ErrorKind, other: Self) -> Self {
+        return other;
+    }
+
+    fn from_char(input: &'a str, c: char) -> Self {
+        return Error::expected(input, format!("{:?}", c));
+    }
+
+    /// Keeps the alternative that went the furthest.
+    fn or(self, other: Self) -> Self {
+        if self.rest.len() < other.rest.len() {
+            return self;
+        }
+        return other;
+    }
+}
+
+/// A context describes the error only when nothing was parsed below it, the
+/// deeper errors being more precise.
+impl<'a> ContextError<&'a str> for Error<'a> {
+    fn add_context(input: &'a str, context: &'static str, mut other: Self) -> Self {
+        if other.rest.len() == input.len() {
+            other.expected = Some(context.to_string());
+        }
+        return other;
+    }
+}
+
+impl<'a, E: fmt::Display> FromExternalError<&'a str, E> for Error<'a> {
+    fn from_external_error(input: &'a str, _: ErrorKind, error: E) -> Self {
+        return Error {
+            rest: input,
+            expected: None,
+            reason: Some(error.to_string()),
+        };
+    }
+}
+
+/// Runs `parser` on the whole input, trailing line breaks excepted.
+pub fn parse_all<'a, T>(
+    mut parser: impl FnMut(&'a str) -> PResult<'a, T>,
+    input: &'a str,
+) -> Result<T, AocError> {
+    let input = input.trim_end_matches('\n');
+    let error = match parser(input) {
+        Ok(("", value)) => return Ok(value),
+        Ok((rest, _)) => Error::expected(rest, "end of input"),
+        Err(nom::Err::Error(error) | nom::Err::Failure(error)) => error,
+        Err(nom::Err::Incomplete(_)) => Error::expected("", "more input"),
+    };
+    return Err(AocError::Parse(error.locate(input)));
+}
+
+/// A number such as `42`.
+pub fn unsigned<T: FromStr>(input: &str) -> PResult<'_, T>
+where
+    T::Err: fmt::Display,
+{
+    return context("a number", map_res(digit1, str::parse))(input);
+}
+
+/// A number with an optional sign, such as `-3` or `+7`.
+pub fn signed<T: FromStr>(input: &str) -> PResult<'_, T>
+where
+    T::Err: fmt::Display,
+{
+    let number = recognize(pair(opt(one_of("+-")), digit1));
+    return context("a signed number", map_res(number, str::parse))(input);
+}
+
+/// Succeeds without consuming anything at the end of a line or of the input.
+pub fn line_end(input: &str) -> PResult<'_, ()> {
+    if input.is_empty() || input.starts_with('\n') {
+        return Ok((input, ()));
+    }
+    return Err(nom::Err::Error(Error::expected(input, "end of line")));
+}
+
+/// Once a separator is found, an element must follow it: its errors become
+/// failures so that they are reported where they happened.
+fn separated<'a, T>(
+    mut element: impl FnMut(&'a str) -> PResult<'a, T>,
+    separator: fn(&'a str) -> Option<&'a str>,
+) -> impl FnMut(&'a str) -> PResult<'a, Vec<T>> {
+    return move |input| {
+        let (mut rest, first) = element(input)?;
+        let mut elements = vec![first];
+        while let Some(next) = separator(rest) {
+            let (after, value) = element(next).map_err(|error| match error {
+                nom::Err::Error(error) => nom::Err::Failure(error),
+                other => other,
+            })?;
+            elements.push(value);
+            rest = after;
+        }
+        return Ok((rest, elements));
+    };
+}
+
+/// One element per line, each filling its line. Stops before a blank line.
+pub fn lines<'a, T>(
+    mut line: impl FnMut(&'a str) -> PResult<'a, T>,
+) -> impl FnMut(&'a str) -> PResult<'a, Vec<T>> {
+    return separated(
+        move |input| {
+            let (rest, value) = line(input)?;
+            let (rest, _) = line_end(rest)?;
+            return Ok((rest, value));
+        },
+        |rest| {
+            rest.strip_prefix('\n')
+                .filter(|next| !next.is_empty() && !next.starts_with('\n'))
+        },
+    );
+}
+
+/// Groups of lines separated by a blank line.
+pub fn blocks<'a, T>(
+    block: impl FnMut(&'a str) -> PResult<'a, T>,
+) -> impl FnMut(&'a str) -> PResult<'a, Vec<T>> {
+    return separated(block, |rest| {
+        rest.strip_prefix("\n\n").filter(|next| !next.is_empty())
+    });
+}
+
+/// Elements separated by a comma and optional spaces, such as `1, 2,3`.
+pub fn comma_list<'a, T>(
+    element: impl FnMut(&'a str) -> PResult<'a, T>,
+) -> impl FnMut(&'a str) -> PResult<'a, Vec<T>> {
+    return separated(element, |rest| {
+        rest.strip_prefix(',')
+            .map(|next| next.trim_start_matches(' '))
+    });
+}
+
+/// A keyword followed by spaces and a value, such as `move 3`. Leading spaces
+/// are skipped so that keywords can be chained on a line.
+pub fn keyword_value<'a, T>(
+    keyword: &'static str,
+    mut value: impl FnMut(&'a str) -> PResult<'a, T>,
+) -> impl FnMut(&'a str) -> PResult<'a, T> {
+    return move |input| {
+        let (input, _) = space0(input)?;
+        let rest = input
+            .strip_prefix(keyword)
+            .ok_or_else(|| nom::Err::Error(Error::expected(input, format!("{:?}", keyword))))?;
+        let (rest, _) = space1(rest)?;
+        return value(rest);
+    };
+}
+
+fn digit_row(input: &str) -> PResult<'_, (&str, Vec<u32>)> {
+    let (rest, digits) = many0(satisfy(|c| c.is_ascii_digit()))(input)?;
+    let (rest, _) = context("a digit", line_end)(rest)?;
+    let digits = digits
+        .into_iter()
+        .map(|digit| digit.to_digit(10).unwrap_or_default())
+        .collect();
+    return Ok((rest, (input, digits)));
+}
+
+/// Rows of single digits, all of the same width.
+pub fn digit_grid(input: &str) -> PResult<'_, Grid<u32>> {
+    let (rest, rows) = lines(digit_row)(input)?;
+    let width = rows[0].1.len();
+    let mut cells = Vec::new();
+    for (row, digits) in rows {
+        if digits.is_empty() || digits.len() != width {
+            let expected = match width {
+                0 => "a digit".to_string(),
+                width => format!("{} digits per row", width),
+            };
+            return Err(nom::Err::Failure(Error::expected(
+                &row[digits.len().min(width)..],
+                expected,
+            )));
+        }
+        cells.extend(digits);
+    }
+    let grid = Grid::new(width, cells).expect("rows of the same width fill the grid");
+    return Ok((rest, grid));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(error: AocError) -> (usize, usize, String) {
+        return match error {
+            AocError::Parse(ParseError {
+                message,
+                location: Some(Location { line, column, .. }),
+            }) => (line, column, message),
+            other => panic!("not a located parse error: {:?}", other),
+        };
+    }
+
+    #[test]
+    fn should_parse_numbers() {
+        assert_eq!(parse_all(unsigned::<u32>, "42"), Ok(42));
+        assert_eq!(parse_all(signed::<i32>, "-42"), Ok(-42));
+        assert_eq!(parse_all(signed::<i32>, "+7"), Ok(7));
+        assert_eq!(
+            message(parse_all(unsigned::<u32>, "-1").unwrap_err()),
+            (1, 1, "expected a number, found \"-1\"".to_string())
+        );
+        assert_eq!(
+            message(parse_all(unsigned::<u8>, "300").unwrap_err()),
+            (
+                1,
+                1,
+                "expected a number: number too large to fit in target type".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn should_locate_errors_in_lines_and_blocks() {
+        let numbers = || blocks(lines(unsigned::<u32>));
+        assert_eq!(
+            parse_all(numbers(), "1\n2\n\n3\n"),
+            Ok(vec![vec![1, 2], vec![3]])
+        );
+        assert_eq!(
+            message(parse_all(numbers(), "1\n2\n\n3\nx4").unwrap_err()),
+            (5, 1, "expected a number, found \"x4\"".to_string())
+        );
+        assert_eq!(
+            message(parse_all(numbers(), "1\n2 3").unwrap_err()),
+            (2, 2, "expected end of line, found \" 3\"".to_string())
+        );
+        assert_eq!(
+            message(parse_all(lines(unsigned::<u32>), "1\n\n2").unwrap_err()),
+            (
+                1,
+                2,
+                "expected end of input, found a blank line".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn should_keep_the_offending_line() {
+        assert_eq!(
+            parse_all(lines(unsigned::<u32>), "1\n22\n3é3\n4"),
+            Err(AocError::Parse(ParseError {
+                message: "expected end of line, found \"é3\"".to_string(),
+                location: Some(Location {
+                    line: 3,
+                    column: 2,
+                    text: "3é3".to_string()
+                })
+            }))
+        );
+    }
+
+    #[test]
+    fn should_parse_keywords_and_lists() {
+        let mut instruction = nom::sequence::tuple((
+            keyword_value("move", unsigned::<u32>),
+            keyword_value("from", unsigned::<u32>),
+        ));
+        assert_eq!(instruction("move 3 from 1"), Ok(("", (3, 1))));
+        assert_eq!(
+            message(parse_all(instruction, "move 3 to 1").unwrap_err()),
+            (1, 8, "expected \"from\", found \"to 1\"".to_string())
+        );
+        assert_eq!(
+            parse_all(comma_list(signed::<i64>), "1, -2,3"),
+            Ok(vec![1, -2, 3])
+        );
+        assert_eq!(
+            message(parse_all(comma_list(signed::<i64>), "1, 2,").unwrap_err()),
+            (
+                1,
+                6,
+                "expected a signed number, found end of input".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn should_parse_digit_grids() {
+        let grid = parse_all(digit_grid, "123\n456\n").unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(
+            message(parse_all(digit_grid, "123\n4é6").unwrap_err()),
+            (2, 2, "expected a digit, found \"é6\"".to_string())
+        );
+        assert_eq!(
+            message(parse_all(digit_grid, "123\n45").unwrap_err()),
+            (
+                2,
+                3,
+                "expected 3 digits per row, found end of input".to_string()
+            )
+        );
+        assert_eq!(
+            message(parse_all(digit_grid, "").unwrap_err()),
+            (1, 1, "expected a digit, found end of input".to_string())
+        );
+    }
+}
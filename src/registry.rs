@@ -0,0 +1,63 @@
+use crate::{solution::Puzzle, y2022};
+
+/// The days of one year of the calendar.
+pub struct Year {
+    pub year: u16,
+    /// In calendar order.
+    pub days: &'static [&'static dyn Puzzle],
+}
+
+/// Every year that has solutions, oldest first.
+pub static YEARS: &[Year] = &[Year {
+    year: 2022,
+    days: y2022::DAYS,
+}];
+
+/// The year the tools work on when none is given.
+pub fn latest_year() -> u16 {
+    return YEARS.last().map_or(0, |year| year.year);
+}
+
+pub fn days(year: u16) -> Option<&'static [&'static dyn Puzzle]> {
+    return YEARS
+        .iter()
+        .find(|registered| registered.year == year)
+        .map(|registered| registered.days);
+}
+
+pub fn find(year: u16, day: u8) -> Option<&'static dyn Puzzle> {
+    return days(year)?
+        .iter()
+        .copied()
+        .find(|puzzle| puzzle.day() == day);
+}
+
+/// The days of every year.
+pub fn all() -> impl Iterator<Item = &'static dyn Puzzle> {
+    return YEARS.iter().flat_map(|year| year.days.iter().copied());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_register_days_in_order_without_duplicates() {
+        for year in YEARS {
+            let days: Vec<u8> = year.days.iter().map(|puzzle| puzzle.day()).collect();
+            let mut sorted = days.clone();
+            sorted.sort();
+            sorted.dedup();
+            assert_eq!(days, sorted);
+            assert!(year.days.iter().all(|puzzle| puzzle.year() == year.year));
+        }
+    }
+
+    #[test]
+    fn should_find_a_registered_day() {
+        assert_eq!(find(2022, 7).map(|puzzle| puzzle.day()), Some(7));
+        assert!(find(2022, 0).is_none());
+        assert!(find(2015, 7).is_none());
+        assert!(days(latest_year()).is_some());
+    }
+}
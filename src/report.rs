@@ -0,0 +1,357 @@
+use std::{fmt, str::FromStr, time::Duration};
+
+use serde::Serialize;
+
+use crate::{bench::format_duration, error::AocError, memory::Memory, runner::RunResult, table};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    /// One `Day N part P: answer` line per part, errors going to stderr.
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        return match value {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            other => Err(format!("unknown format {}", other)),
+        };
+    }
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Format::Text => write!(f, "text"),
+            Format::Json => write!(f, "json"),
+            Format::Csv => write!(f, "csv"),
+        }
+    }
+}
+
+/// A [`RunResult`] flattened for the machine readable formats.
+#[derive(Debug, Serialize)]
+struct Record {
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: Option<String>,
+    parse_time_ns: u64,
+    solve_time_ns: Option<u64>,
+    error: Option<String>,
+    parse_memory: Option<Memory>,
+    solve_memory: Option<Memory>,
+}
+
+fn nanos(duration: Duration) -> u64 {
+    return duration.as_nanos() as u64;
+}
+
+impl From<&RunResult> for Record {
+    fn from(result: &RunResult) -> Self {
+        return Record {
+            year: result.year,
+            day: result.day,
+            part: result.part.number(),
+            answer: result.answer.as_ref().ok().map(|answer| answer.to_string()),
+            parse_time_ns: nanos(result.parse_time),
+            solve_time_ns: result.solve_time.map(nanos),
+            error: result.answer.as_ref().err().map(|error| error.to_string()),
+            parse_memory: result.parse_memory,
+            solve_memory: result.solve_memory,
+        };
+    }
+}
+
+pub fn render_json(results: &[RunResult]) -> String {
+    let records: Vec<Record> = results.iter().map(Record::from).collect();
+    return serde_json::to_string_pretty(&records).expect("Unable to serialize the results");
+}
+
+/// Quotes the fields containing a separator, a quote or a line break.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        return format!("\"{}\"", field.replace('"', "\"\""));
+    }
+    return field.to_string();
+}
+
+fn memory_fields(memory: Option<Memory>) -> [String; 3] {
+    return match memory {
+        Some(memory) => [
+            memory.allocations.to_string(),
+            memory.allocated.to_string(),
+            memory.peak.to_string(),
+        ],
+        None => Default::default(),
+    };
+}
+
+pub fn render_csv(results: &[RunResult]) -> String {
+    let mut lines = vec![[
+        "year,day,part,answer,parse_time_ns,solve_time_ns,error",
+        "parse_allocations,parse_allocated_bytes,parse_peak_bytes",
+        "solve_allocations,solve_allocated_bytes,solve_peak_bytes",
+    ]
+    .join(",")];
+    for record in results.iter().map(Record::from) {
+        let [parse_allocations, parse_allocated, parse_peak] = memory_fields(record.parse_memory);
+        let [solve_allocations, solve_allocated, solve_peak] = memory_fields(record.solve_memory);
+        let fields = [
+            record.year.to_string(),
+            record.day.to_string(),
+            record.part.to_string(),
+            record.answer.unwrap_or_default(),
+            record.parse_time_ns.to_string(),
+            record
+                .solve_time_ns
+                .map(|time| time.to_string())
+                .unwrap_or_default(),
+            record.error.unwrap_or_default(),
+            parse_allocations,
+            parse_allocated,
+            parse_peak,
+            solve_allocations,
+            solve_allocated,
+            solve_peak,
+        ];
+        lines.push(
+            fields
+                .iter()
+                .map(|field| csv_field(field))
+                .collect::<Vec<String>>()
+                .join(","),
+        );
+    }
+    return lines.join("\n");
+}
+
+pub fn format_bytes(bytes: u64) -> String {
+    return if bytes < 1 << 10 {
+        format!("{}B", bytes)
+    } else if bytes < 1 << 20 {
+        format!("{:.2}KiB", bytes as f64 / 1024.0)
+    } else if bytes < 1 << 30 {
+        format!("{:.2}MiB", bytes as f64 / (1024.0 * 1024.0))
+    } else {
+        format!("{:.2}GiB", bytes as f64 / (1024.0 * 1024.0 * 1024.0))
+    };
+}
+
+/// `12 allocations, 1.50KiB allocated, 1.00KiB peak`.
+pub fn format_memory(memory: &Memory) -> String {
+    return format!(
+        "{} allocations, {} allocated, {} peak",
+        memory.allocations,
+        format_bytes(memory.allocated),
+        format_bytes(memory.peak)
+    );
+}
+
+fn status(result: &RunResult) -> &'static str {
+    return match &result.answer {
+        Ok(_) => "ok",
+        Err(AocError::NotImplemented { .. }) => "not implemented",
+        Err(AocError::Panicked(_)) => "panicked",
+        Err(AocError::TimedOut { .. }) => "timed out",
+        Err(_) => "failed",
+    };
+}
+
+/// One row per part with its answer or error, and a line of totals taking
+/// `elapsed` as the wall-clock time of the whole run. The allocations of the
+/// parts are shown when they were counted.
+pub fn render_summary(results: &[RunResult], elapsed: Duration) -> String {
+    let counted = results.iter().any(|result| result.solve_memory.is_some());
+    let rows: Vec<Vec<String>> = results
+        .iter()
+        .map(|result| {
+            let mut row = vec![
+                result.day.to_string(),
+                result.part.to_string(),
+                status(result).to_string(),
+                match &result.answer {
+                    Ok(answer) => answer.to_string(),
+                    Err(AocError::NotImplemented { .. }) => String::new(),
+                    Err(error) => error.to_string(),
+                },
+                format_duration(result.parse_time),
+                result.solve_time.map(format_duration).unwrap_or_default(),
+            ];
+            if let Some(memory) = result.solve_memory {
+                row.extend([
+                    memory.allocations.to_string(),
+                    format_bytes(memory.allocated),
+                    format_bytes(memory.peak),
+                ]);
+            }
+            return row;
+        })
+        .collect();
+
+    let mut headers = vec!["Day", "Part", "Status", "Answer", "Parse", "Solve"];
+    if counted {
+        headers.extend(["Allocs", "Allocated", "Peak"]);
+    }
+
+    let count = |name: &str| results.iter().filter(|r| status(r) == name).count();
+    let failed = results.iter().filter(|r| r.is_failure(false)).count();
+    return format!(
+        "{}\n\n{} answered, {} not implemented, {} failed in {}",
+        table::render(&headers, &rows),
+        count("ok"),
+        count("not implemented"),
+        failed,
+        format_duration(elapsed)
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        error::AocError,
+        solution::{Answer, Part},
+    };
+
+    fn results() -> Vec<RunResult> {
+        return vec![
+            RunResult {
+                year: 2022,
+                day: 5,
+                part: Part::One,
+                answer: Ok(Answer::from("CMZ")),
+                parse_time: Duration::from_nanos(1500),
+                solve_time: Some(Duration::from_nanos(200)),
+                parse_memory: Some(Memory {
+                    allocations: 3,
+                    allocated: 1536,
+                    peak: 1024,
+                }),
+                solve_memory: Some(Memory {
+                    allocations: 2,
+                    allocated: 100,
+                    peak: 64,
+                }),
+            },
+            RunResult {
+                year: 2022,
+                day: 5,
+                part: Part::Two,
+                answer: Err(AocError::validation("no stacks, \"really\"")),
+                parse_time: Duration::from_nanos(1500),
+                solve_time: None,
+                parse_memory: Some(Memory {
+                    allocations: 3,
+                    allocated: 1536,
+                    peak: 1024,
+                }),
+                solve_memory: None,
+            },
+        ];
+    }
+
+    #[test]
+    fn should_parse_formats() {
+        assert_eq!("csv".parse::<Format>(), Ok(Format::Csv));
+        assert!("xml".parse::<Format>().is_err());
+    }
+
+    #[test]
+    fn should_render_json() {
+        let json: serde_json::Value = serde_json::from_str(&render_json(&results())).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!([
+                {
+                    "year": 2022,
+                    "day": 5,
+                    "part": 1,
+                    "answer": "CMZ",
+                    "parse_time_ns": 1500,
+                    "solve_time_ns": 200,
+                    "error": null,
+                    "parse_memory": {"allocations": 3, "allocated": 1536, "peak": 1024},
+                    "solve_memory": {"allocations": 2, "allocated": 100, "peak": 64}
+                },
+                {
+                    "year": 2022,
+                    "day": 5,
+                    "part": 2,
+                    "answer": null,
+                    "parse_time_ns": 1500,
+                    "solve_time_ns": null,
+                    "error": "invalid input: no stacks, \"really\"",
+                    "parse_memory": {"allocations": 3, "allocated": 1536, "peak": 1024},
+                    "solve_memory": null
+                }
+            ])
+        );
+    }
+
+    #[test]
+    fn should_render_csv() {
+        assert_eq!(
+            render_csv(&results()),
+            "year,day,part,answer,parse_time_ns,solve_time_ns,error,\
+parse_allocations,parse_allocated_bytes,parse_peak_bytes,\
+solve_allocations,solve_allocated_bytes,solve_peak_bytes
+2022,5,1,CMZ,1500,200,,3,1536,1024,2,100,64
+2022,5,2,,1500,,\"invalid input: no stacks, \"\"really\"\"\",3,1536,1024,,,"
+        );
+    }
+
+    #[test]
+    fn should_tell_the_same_day_of_two_years_apart() {
+        let mut results = results();
+        results[1].year = 2023;
+        let json: serde_json::Value = serde_json::from_str(&render_json(&results)).unwrap();
+        assert_eq!(json[0]["year"], 2022);
+        assert_eq!(json[1]["year"], 2023);
+        let csv = render_csv(&results);
+        let rows: Vec<&str> = csv.lines().skip(1).collect();
+        assert!(rows[0].starts_with("2022,5,1,"));
+        assert!(rows[1].starts_with("2023,5,2,"));
+    }
+
+    #[test]
+    fn should_format_bytes() {
+        assert_eq!(format_bytes(512), "512B");
+        assert_eq!(format_bytes(1536), "1.50KiB");
+        assert_eq!(format_bytes(3 << 20), "3.00MiB");
+    }
+
+    #[test]
+    fn should_render_a_summary() {
+        let mut results = results();
+        results.push(RunResult {
+            year: 2022,
+            day: 8,
+            part: Part::Two,
+            answer: Err(AocError::NotImplemented {
+                day: 8,
+                part: Part::Two,
+            }),
+            parse_time: Duration::from_micros(20),
+            solve_time: Some(Duration::ZERO),
+            parse_memory: None,
+            solve_memory: None,
+        });
+        assert_eq!(
+            render_summary(&results, Duration::from_millis(3)),
+            "Day  Part  Status           Answer                              Parse    Solve  Allocs  Allocated  Peak
+5    1     ok               CMZ                                 1.50µs   200ns  2       100B       64B
+5    2     failed           invalid input: no stacks, \"really\"  1.50µs
+8    2     not implemented                                      20.00µs  0ns
+
+1 answered, 1 not implemented, 1 failed in 3.00ms"
+        );
+    }
+}
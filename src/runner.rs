@@ -0,0 +1,372 @@
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc::{self, RecvTimeoutError},
+        Arc, Once,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    error::AocError,
+    input::InputProvider,
+    memory::{self, Memory},
+    solution::{Answer, Part, Puzzle},
+};
+
+/// The outcome of running one part, with the time spent in each phase.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RunResult {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub answer: Result<Answer, AocError>,
+    /// Shared by the parts of a day, which are solved on the same parsed input.
+    pub parse_time: Duration,
+    /// `None` when the part did not run to completion.
+    pub solve_time: Option<Duration>,
+    /// Only counted when built with the `count-allocations` feature.
+    pub parse_memory: Option<Memory>,
+    pub solve_memory: Option<Memory>,
+}
+
+impl RunResult {
+    /// Unsolved parts only count as a failure when they were explicitly requested.
+    pub fn is_failure(&self, explicit: bool) -> bool {
+        return match &self.answer {
+            Ok(_) => false,
+            Err(AocError::NotImplemented { .. }) => explicit,
+            Err(_) => true,
+        };
+    }
+}
+
+/// The same error for every part, when the day could not get as far as solving them.
+fn failed(
+    puzzle: &dyn Puzzle,
+    parts: &[Part],
+    error: AocError,
+    parse_time: Duration,
+) -> Vec<RunResult> {
+    return parts
+        .iter()
+        .map(|&part| RunResult {
+            year: puzzle.year(),
+            day: puzzle.day(),
+            part,
+            answer: Err(error.clone()),
+            parse_time,
+            solve_time: None,
+            parse_memory: None,
+            solve_memory: None,
+        })
+        .collect();
+}
+
+/// How long a parse or a part may run before it is reported as timed out.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// `panic!` payloads are a `&str` or a `String` unless something else was thrown.
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<String>() {
+        return message.clone();
+    }
+    return match payload.downcast_ref::<&str>() {
+        Some(message) => message.to_string(),
+        None => "unknown panic payload".to_string(),
+    };
+}
+
+thread_local! {
+    /// Whether a panic on this thread is caught by [`catch_panic`].
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+    /// The message of the last panic caught on this thread.
+    static CAUGHT: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+static QUIET_HOOK: Once = Once::new();
+
+/// Runs `task` and returns the message of its panic, if any. The panic hook
+/// keeps the message instead of printing it with a backtrace, panics outside
+/// of `catch_panic` still go to the previous hook.
+pub fn catch_panic<T>(task: impl FnOnce() -> T) -> Result<T, String> {
+    QUIET_HOOK.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if CATCHING.try_with(Cell::get).unwrap_or(false) {
+                CAUGHT.with(|caught| *caught.borrow_mut() = Some(panic_message(info.payload())));
+            } else {
+                previous(info);
+            }
+        }));
+    });
+    let catching = CATCHING.replace(true);
+    let outcome = panic::catch_unwind(AssertUnwindSafe(task));
+    CATCHING.set(catching);
+    return outcome.map_err(|payload| {
+        return CAUGHT
+            .take()
+            .unwrap_or_else(|| panic_message(payload.as_ref()));
+    });
+}
+
+/// What a step returned, with the resources it took.
+pub struct Step<T> {
+    pub value: T,
+    pub time: Duration,
+    pub memory: Option<Memory>,
+}
+
+/// Runs `task` on its own thread and returns what it took to complete, unless
+/// it panics or runs longer than `timeout`. A thread that timed out cannot be
+/// stopped and is left running in the background.
+pub fn guarded<T: Send + 'static>(
+    timeout: Duration,
+    task: impl FnOnce() -> T + Send + 'static,
+) -> Result<Step<T>, AocError> {
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let start = Instant::now();
+        let (outcome, memory) = memory::measure(|| catch_panic(task));
+        let time = start.elapsed();
+        let step = outcome.map(|value| Step {
+            value,
+            time,
+            memory,
+        });
+        let _ = sender.send(step);
+    });
+    return match receiver.recv_timeout(timeout) {
+        Ok(Ok(done)) => Ok(done),
+        Ok(Err(message)) => Err(AocError::Panicked(message)),
+        Err(RecvTimeoutError::Timeout) => Err(AocError::TimedOut { after: timeout }),
+        Err(RecvTimeoutError::Disconnected) => Err(AocError::Panicked(
+            "the solution thread stopped without an answer".to_string(),
+        )),
+    };
+}
+
+pub type Parsed = Box<dyn Any + Send>;
+
+pub fn parse(
+    puzzle: &'static dyn Puzzle,
+    input: &Arc<String>,
+    timeout: Duration,
+) -> Result<Step<Parsed>, AocError> {
+    let input = Arc::clone(input);
+    let step = guarded(timeout, move || puzzle.parse(&input))?;
+    return step.value.map(|parsed| Step {
+        value: parsed,
+        time: step.time,
+        memory: step.memory,
+    });
+}
+
+/// Loads and parses the input of the puzzle once, then solves each part. Each
+/// step runs under `timeout`, and a panic fails the step instead of the run.
+/// The parsed input is moved to the thread solving a part and back, so it is
+/// only parsed again after a part panicked or timed out with it.
+pub fn run_puzzle(
+    puzzle: &'static dyn Puzzle,
+    parts: &[Part],
+    inputs: &InputProvider,
+    timeout: Duration,
+) -> Vec<RunResult> {
+    let day = puzzle.day();
+    let failed = |error: AocError, parse_time: Duration| -> Vec<RunResult> {
+        return failed(puzzle, parts, error, parse_time);
+    };
+
+    let input = match inputs.load(puzzle.year(), day) {
+        Ok(input) => Arc::new(input),
+        Err(error) => return failed(error, Duration::ZERO),
+    };
+
+    let (parsed, parse_time, parse_memory) = match parse(puzzle, &input, timeout) {
+        Ok(step) => (step.value, step.time, step.memory),
+        Err(error) => return failed(error, Duration::ZERO),
+    };
+
+    let mut parsed = Some(parsed);
+    return parts
+        .iter()
+        .map(|&part| {
+            let solved = match parsed.take() {
+                Some(parsed) => Ok(parsed),
+                None => parse(puzzle, &input, timeout).map(|step| step.value),
+            }
+            .and_then(|owned| {
+                guarded(timeout, move || {
+                    let answer = puzzle.solve(part, owned.as_ref());
+                    return (answer, owned);
+                })
+            });
+            let (answer, solve_time, solve_memory) = match solved {
+                Ok(Step {
+                    value: (answer, owned),
+                    time,
+                    memory,
+                }) => {
+                    parsed = Some(owned);
+                    (answer, Some(time), memory)
+                }
+                Err(error) => (Err(error), None, None),
+            };
+            RunResult {
+                year: puzzle.year(),
+                day,
+                part,
+                answer,
+                parse_time,
+                solve_time,
+                parse_memory,
+                solve_memory,
+            }
+        })
+        .collect();
+}
+
+/// Runs every part of the puzzles on `jobs` threads, each day being one task.
+/// The results are in the order of `puzzles`, whatever order the tasks
+/// finished in.
+pub fn run_all(
+    puzzles: &[&'static dyn Puzzle],
+    inputs: &InputProvider,
+    jobs: usize,
+    timeout: Duration,
+) -> Vec<RunResult> {
+    let next = AtomicUsize::new(0);
+    let worker = || -> Vec<(usize, Vec<RunResult>)> {
+        let mut done = Vec::new();
+        loop {
+            let index = next.fetch_add(1, Ordering::Relaxed);
+            let puzzle = match puzzles.get(index) {
+                Some(puzzle) => *puzzle,
+                None => return done,
+            };
+            done.push((index, run_puzzle(puzzle, &Part::ALL, inputs, timeout)));
+        }
+    };
+
+    let mut done: Vec<(usize, Vec<RunResult>)> = thread::scope(|scope| {
+        let workers: Vec<_> = (0..jobs.clamp(1, puzzles.len().max(1)))
+            .map(|_| scope.spawn(worker))
+            .collect();
+        return workers
+            .into_iter()
+            .flat_map(|worker| worker.join().expect("A worker thread panicked"))
+            .collect();
+    });
+    done.sort_by_key(|(index, _)| *index);
+    return done.into_iter().flat_map(|(_, results)| results).collect();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{Endless, Panicking, Sum};
+
+    fn input_file(name: &str, input: &str) -> InputProvider {
+        let path = std::env::temp_dir().join(format!("aoc-runner-{}-{}", name, std::process::id()));
+        std::fs::write(&path, input).unwrap();
+        return InputProvider::new().with_path(path);
+    }
+
+    fn run_sum(name: &str, input: &str) -> Vec<RunResult> {
+        return run_puzzle(&Sum, &Part::ALL, &input_file(name, input), DEFAULT_TIMEOUT);
+    }
+
+    #[test]
+    fn should_solve_each_part_on_the_parsed_input() {
+        let results = run_sum("solve", "1\n2");
+        assert_eq!(results[0].answer, Ok(Answer::from(3)));
+        assert!(results[0].solve_time.is_some());
+        assert!(!results[0].is_failure(true));
+        assert!(!results[1].is_failure(false));
+        assert!(results[1].is_failure(true));
+    }
+
+    #[test]
+    fn should_report_parse_errors_for_every_part() {
+        let results = run_sum("parse", "1\ntwo");
+        assert_eq!(results.len(), 2);
+        assert!(results
+            .iter()
+            .all(|result| matches!(result.answer, Err(AocError::Parse(_)))
+                && result.solve_time.is_none()
+                && result.is_failure(false)));
+    }
+
+    #[test]
+    fn should_isolate_panics_and_keep_the_order() {
+        let inputs = input_file("all", "1\n2");
+        let puzzles: [&'static dyn Puzzle; 3] = [&Sum, &Panicking, &Sum];
+
+        let results = run_all(&puzzles, &inputs, 2, DEFAULT_TIMEOUT);
+        let days: Vec<u8> = results.iter().map(|result| result.day).collect();
+        assert_eq!(days, vec![1, 1, 2, 2, 1, 1]);
+        assert_eq!(results[0].answer, Ok(Answer::from(3)));
+        assert_eq!(
+            results[2].answer,
+            Err(AocError::Panicked("not yet".to_string()))
+        );
+        assert!(matches!(
+            results[3].answer,
+            Err(AocError::NotImplemented { .. })
+        ));
+        assert_eq!(results[4].answer, Ok(Answer::from(3)));
+        let sequential: Vec<_> = run_all(&puzzles, &inputs, 1, DEFAULT_TIMEOUT)
+            .into_iter()
+            .map(|result| result.answer)
+            .collect();
+        let answers: Vec<_> = results.into_iter().map(|result| result.answer).collect();
+        assert_eq!(sequential, answers);
+    }
+
+    #[test]
+    fn should_fail_a_panicking_part_only() {
+        let inputs = input_file("panicking", "");
+        let results = run_puzzle(&Panicking, &Part::ALL, &inputs, DEFAULT_TIMEOUT);
+        assert_eq!(
+            results[0].answer,
+            Err(AocError::Panicked("not yet".to_string()))
+        );
+        assert!(results[0].is_failure(false));
+        assert!(matches!(
+            results[1].answer,
+            Err(AocError::NotImplemented { .. })
+        ));
+        assert!(!results[1].is_failure(false));
+    }
+
+    #[test]
+    fn should_catch_a_panic_message_through_the_hook() {
+        assert_eq!(catch_panic(|| 1), Ok(1));
+        assert_eq!(
+            catch_panic(|| panic!("day {} failed", 8)),
+            Err::<(), _>("day 8 failed".to_string())
+        );
+        assert_eq!(
+            catch_panic(|| panic::panic_any(8)),
+            Err::<(), _>("unknown panic payload".to_string())
+        );
+        assert!(!CATCHING.get());
+        assert_eq!(CAUGHT.take(), None);
+    }
+
+    #[test]
+    fn should_time_out_a_part_and_still_solve_the_next_one() {
+        let timeout = Duration::from_millis(50);
+        let results = run_puzzle(&Endless, &Part::ALL, &input_file("endless", ""), timeout);
+        assert_eq!(
+            results[0].answer,
+            Err(AocError::TimedOut { after: timeout })
+        );
+        assert!(results[0].solve_time.is_none());
+        assert_eq!(results[1].answer, Ok(Answer::from(2)));
+    }
+}
@@ -0,0 +1,548 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{error::AocError, input::relative_path};
+
+/// Every `YYYY` of the template is replaced by the year and every `NN` by
+/// the day number.
+const TEMPLATE: &str = "use crate::{
+    error::AocError,
+    solution::{Answer, Part, Solution},
+};
+
+/// One entry per line.
+pub fn parse(input: &str) -> Result<Vec<String>, AocError> {
+    return Ok(input.lines().map(|line| line.to_string()).collect());
+}
+
+pub fn part1(_lines: &[String]) -> Result<u64, AocError> {
+    return Err(AocError::NotImplemented {
+        day: NN,
+        part: Part::One,
+    });
+}
+
+pub fn part2(_lines: &[String]) -> Result<u64, AocError> {
+    return Err(AocError::NotImplemented {
+        day: NN,
+        part: Part::Two,
+    });
+}
+
+pub struct DayNN;
+
+impl Solution for DayNN {
+    type Input = Vec<String>;
+
+    const YEAR: u16 = YYYY;
+
+    const DAY: u8 = NN;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        return parse(input);
+    }
+
+    fn part1(&self, lines: &Self::Input) -> Result<Answer, AocError> {
+        return part1(lines).map(Answer::from);
+    }
+
+    fn part2(&self, lines: &Self::Input) -> Result<Answer, AocError> {
+        return part2(lines).map(Answer::from);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::answers::known_answer;
+    use crate::input::{read_input, read_variant};
+    use crate::solution::Puzzle;
+
+    #[test]
+    #[ignore = \"not solved yet\"]
+    fn part1_example() {
+        let input = &read_variant(YYYY, NN, \"example\").unwrap();
+        assert_eq!(DayNN.run(Part::One, input), Ok(0.into()));
+    }
+
+    #[test]
+    #[ignore = \"not solved yet\"]
+    fn part1_res() {
+        let input = &read_input(YYYY, NN).unwrap();
+        assert_eq!(
+            DayNN.run(Part::One, input),
+            Ok(known_answer(YYYY, NN, Part::One))
+        );
+    }
+
+    #[test]
+    #[ignore = \"not solved yet\"]
+    fn part2_example() {
+        let input = &read_variant(YYYY, NN, \"example\").unwrap();
+        assert_eq!(DayNN.run(Part::Two, input), Ok(0.into()));
+    }
+
+    #[test]
+    #[ignore = \"not solved yet\"]
+    fn part2_res() {
+        let input = &read_input(YYYY, NN).unwrap();
+        assert_eq!(
+            DayNN.run(Part::Two, input),
+            Ok(known_answer(YYYY, NN, Part::Two))
+        );
+    }
+}
+";
+
+/// The module of a new year, holding its first day.
+const YEAR_TEMPLATE: &str = "//! Advent of Code YYYY.
+
+use crate::solution::Puzzle;
+
+pub mod day_NN;
+
+/// Every day that has a solution, in calendar order.
+pub static DAYS: &[&dyn Puzzle] = &[&day_NN::DayNN];
+";
+
+/// Width rustfmt wraps the lines at.
+const MAX_WIDTH: usize = 100;
+
+/// Width of the elements past which rustfmt puts each one on its own line.
+const ARRAY_WIDTH: usize = 60;
+
+fn fill(template: &str, year: u16, day: u8) -> String {
+    return template
+        .replace("YYYY", &year.to_string())
+        .replace("NN", &day.to_string());
+}
+
+/// The stubs of a new day, with ignored tests for the example and the real input.
+pub fn module_source(year: u16, day: u8) -> String {
+    return fill(TEMPLATE, year, day);
+}
+
+/// `src/y2022/mod.rs` for a year whose first day is `day`.
+pub fn year_source(year: u16, day: u8) -> String {
+    return fill(YEAR_TEMPLATE, year, day);
+}
+
+fn io_error(path: &Path, error: std::io::Error) -> AocError {
+    return AocError::Io(format!("unable to access {}: {}", path.display(), error));
+}
+
+/// Adds `pub mod <module>;` to the module declarations, kept in rustfmt order.
+pub fn register_module(source: &str, module: &str) -> Result<String, AocError> {
+    let declaration = format!("pub mod {};", module);
+    if source.lines().any(|line| line == declaration) {
+        return Err(AocError::validation(format!(
+            "{} is already declared",
+            module
+        )));
+    }
+
+    let mut lines: Vec<&str> = source.lines().collect();
+    let first = lines
+        .iter()
+        .position(|line| line.starts_with("pub mod "))
+        .ok_or_else(|| AocError::validation("no module declarations"))?;
+    let index = lines[first..]
+        .iter()
+        .position(|line| match line.strip_prefix("pub mod ") {
+            Some(declared) => declared.trim_end_matches(';') > module,
+            None => true,
+        })
+        .map_or(lines.len(), |offset| first + offset);
+    lines.insert(index, &declaration);
+    return Ok(lines.join("\n") + "\n");
+}
+
+/// Lays out a `use` list the way rustfmt does: on one line when it fits,
+/// otherwise filling indented lines.
+fn format_use(items: &[String]) -> String {
+    let single = format!("use crate::{{{}}};", items.join(", "));
+    if single.len() <= MAX_WIDTH {
+        return single;
+    }
+    let mut lines = vec!["use crate::{".to_string()];
+    let mut line = String::new();
+    for item in items {
+        let item = format!("{},", item);
+        if !line.is_empty() && 4 + line.len() + 1 + item.len() > MAX_WIDTH {
+            lines.push(format!("    {}", line));
+            line = String::new();
+        }
+        if !line.is_empty() {
+            line.push(' ');
+        }
+        line.push_str(&item);
+    }
+    lines.push(format!("    {}", line));
+    lines.push("};".to_string());
+    return lines.join("\n");
+}
+
+/// The source between `start`, included, and the next `end`, included.
+fn span(source: &str, start: &str, end: &str) -> Result<(usize, usize), AocError> {
+    let from = source
+        .find(start)
+        .ok_or_else(|| AocError::validation(format!("no {:?} in the registry", start)))?;
+    let to = from
+        + source[from..]
+            .find(end)
+            .ok_or_else(|| AocError::validation(format!("unterminated {:?}", start)))?
+        + end.len();
+    return Ok((from, to));
+}
+
+/// Adds the day to the `DAYS` of its year module, in calendar order.
+pub fn register_day(year_module: &str, day: u8) -> Result<String, AocError> {
+    let (from, to) = span(year_module, "pub static DAYS", "];")?;
+    let mut days: Vec<u8> = year_module[from..to]
+        .split("&day_")
+        .skip(1)
+        .filter_map(|entry| entry.split_once("::")?.0.parse::<u8>().ok())
+        .collect();
+    if days.contains(&day) {
+        return Err(AocError::validation(format!(
+            "day {} is already registered",
+            day
+        )));
+    }
+    days.push(day);
+    days.sort();
+    let entries: Vec<String> = days
+        .iter()
+        .map(|day| format!("&day_{}::Day{}", day, day))
+        .collect();
+    let single = format!(
+        "pub static DAYS: &[&dyn Puzzle] = &[{}];",
+        entries.join(", ")
+    );
+    let list = if entries.join(", ").len() <= ARRAY_WIDTH && single.len() <= MAX_WIDTH {
+        single
+    } else {
+        format!(
+            "pub static DAYS: &[&dyn Puzzle] = &[\n{}\n];",
+            entries
+                .iter()
+                .map(|entry| format!("    {},", entry))
+                .collect::<Vec<String>>()
+                .join("\n")
+        )
+    };
+    return Ok(format!(
+        "{}{}{}",
+        &year_module[..from],
+        list,
+        &year_module[to..]
+    ));
+}
+
+/// Imports the module of the year in the registry and adds it to `YEARS`,
+/// oldest first.
+pub fn register_year(registry: &str, year: u16) -> Result<String, AocError> {
+    let (from, to) = span(registry, "pub static YEARS", "];")?;
+    let mut years: Vec<u16> = registry[from..to]
+        .split("year: ")
+        .skip(1)
+        .filter_map(|entry| entry.split_once(',')?.0.parse::<u16>().ok())
+        .collect();
+    if years.contains(&year) {
+        return Err(AocError::validation(format!(
+            "{} is already registered",
+            year
+        )));
+    }
+    years.push(year);
+    years.sort();
+    let list = match years.as_slice() {
+        [only] => format!(
+            "pub static YEARS: &[Year] = &[Year {{\n    year: {},\n    days: y{}::DAYS,\n}}];",
+            only, only
+        ),
+        years => format!(
+            "pub static YEARS: &[Year] = &[\n{}];",
+            years
+                .iter()
+                .map(|year| format!(
+                    "    Year {{\n        year: {},\n        days: y{}::DAYS,\n    }},\n",
+                    year, year
+                ))
+                .collect::<String>()
+        ),
+    };
+    let registry = format!("{}{}{}", &registry[..from], list, &registry[to..]);
+
+    let (from, to) = span(&registry, "use crate::{", "};")?;
+    let mut items: Vec<String> = registry[from + "use crate::{".len()..to - "};".len()]
+        .split(',')
+        .map(|item| item.trim().to_string())
+        .filter(|item| !item.is_empty())
+        .collect();
+    items.push(format!("y{}", year));
+    items.sort();
+    return Ok(format!(
+        "{}{}{}",
+        &registry[..from],
+        format_use(&items),
+        &registry[to..]
+    ));
+}
+
+/// What [`new_day`] wrote.
+#[derive(Debug, PartialEq)]
+pub struct Scaffold {
+    pub module: PathBuf,
+    /// Set when the day is the first of its year.
+    pub year_module: Option<PathBuf>,
+    /// Input files created empty, the existing ones being left as they are.
+    pub resources: Vec<PathBuf>,
+}
+
+fn read(path: &Path) -> Result<String, AocError> {
+    return fs::read_to_string(path).map_err(|e| io_error(path, e));
+}
+
+fn write(path: &Path, content: &str) -> Result<(), AocError> {
+    return fs::write(path, content).map_err(|e| io_error(path, e));
+}
+
+/// Creates `src/yYYYY/day_N.rs` with empty `resources/YYYY/dayN.txt` and
+/// `resources/YYYY/dayN-example.txt` under `root`, and registers the day. The
+/// first day of a year also creates `src/yYYYY/mod.rs` and registers the year.
+/// Nothing is written when the module already exists.
+pub fn new_day(root: &Path, year: u16, day: u8) -> Result<Scaffold, AocError> {
+    if !(1..=25).contains(&day) {
+        return Err(AocError::validation(format!(
+            "there is no day {} in the calendar",
+            day
+        )));
+    }
+    if !(2015..=9999).contains(&year) {
+        return Err(AocError::validation(format!(
+            "there is no calendar in {}",
+            year
+        )));
+    }
+
+    let year_dir = root.join("src").join(format!("y{}", year));
+    let module = year_dir.join(format!("day_{}.rs", day));
+    if module.exists() {
+        return Err(AocError::validation(format!(
+            "{} already exists",
+            module.display()
+        )));
+    }
+    let mod_path = year_dir.join("mod.rs");
+    let year_module = if mod_path.exists() {
+        let year_module = read(&mod_path)?;
+        let year_module = register_module(&year_module, &format!("day_{}", day))?;
+        let year_module = register_day(&year_module, day)?;
+        write(&module, &module_source(year, day))?;
+        write(&mod_path, &year_module)?;
+        None
+    } else {
+        let lib_path = root.join("src").join("lib.rs");
+        let registry_path = root.join("src").join("registry.rs");
+        let lib = register_module(&read(&lib_path)?, &format!("y{}", year))?;
+        let registry = register_year(&read(&registry_path)?, year)?;
+        fs::create_dir_all(&year_dir).map_err(|e| io_error(&year_dir, e))?;
+        write(&module, &module_source(year, day))?;
+        write(&mod_path, &year_source(year, day))?;
+        write(&lib_path, &lib)?;
+        write(&registry_path, &registry)?;
+        Some(mod_path)
+    };
+
+    let resources_dir = root.join("resources");
+    let mut resources = Vec::new();
+    for variant in [None, Some("example")] {
+        let path = resources_dir.join(relative_path(year, day, variant));
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|e| io_error(dir, e))?;
+        }
+        if !path.exists() {
+            write(&path, "")?;
+            resources.push(path);
+        }
+    }
+    return Ok(Scaffold {
+        module,
+        year_module,
+        resources,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIB: &str = "#![allow(non_snake_case)]
+
+pub mod answers;
+pub mod error;
+pub mod registry;
+pub mod y2022;
+
+#[cfg(test)]
+mod http_stub;
+";
+
+    const REGISTRY: &str = "use crate::{solution::Puzzle, y2022};
+
+/// Every year that has solutions, oldest first.
+pub static YEARS: &[Year] = &[Year {
+    year: 2022,
+    days: y2022::DAYS,
+}];
+";
+
+    const YEAR_MODULE: &str = "//! Advent of Code 2022.
+
+use crate::solution::Puzzle;
+
+pub mod day_1;
+pub mod day_10;
+pub mod day_2;
+pub mod day_5;
+
+/// Every day that has a solution, in calendar order.
+pub static DAYS: &[&dyn Puzzle] = &[&day_1::Day1, &day_2::Day2, &day_5::Day5, &day_10::Day10];
+";
+
+    #[test]
+    fn should_declare_the_module_in_order() {
+        let module = register_module(YEAR_MODULE, "day_4").unwrap();
+        assert!(module.contains("pub mod day_2;\npub mod day_4;\npub mod day_5;\n"));
+        let module = register_module(&module, "day_25").unwrap();
+        assert!(module.contains("pub mod day_2;\npub mod day_25;\npub mod day_4;\n"));
+        assert!(register_module(&module, "day_4").is_err());
+        let lib = register_module(LIB, "y2023").unwrap();
+        assert!(lib.contains("pub mod y2022;\npub mod y2023;\n\n"));
+    }
+
+    #[test]
+    fn should_register_the_day_in_calendar_order() {
+        let module = register_day(YEAR_MODULE, 4).unwrap();
+        assert!(module.contains(
+            "pub static DAYS: &[&dyn Puzzle] = &[
+    &day_1::Day1,
+    &day_2::Day2,
+    &day_4::Day4,
+    &day_5::Day5,
+    &day_10::Day10,
+];
+"
+        ));
+        let module = register_day(&module, 11).unwrap();
+        assert!(module.contains("    &day_10::Day10,\n    &day_11::Day11,\n];\n"));
+        assert!(register_day(&module, 4).is_err());
+        assert!(register_day(&module, 11).is_err());
+    }
+
+    #[test]
+    fn should_register_the_year() {
+        let registry = register_year(REGISTRY, 2021).unwrap();
+        assert_eq!(
+            registry,
+            "use crate::{solution::Puzzle, y2021, y2022};
+
+/// Every year that has solutions, oldest first.
+pub static YEARS: &[Year] = &[
+    Year {
+        year: 2021,
+        days: y2021::DAYS,
+    },
+    Year {
+        year: 2022,
+        days: y2022::DAYS,
+    },
+];
+"
+        );
+        assert!(register_year(&registry, 2021).is_err());
+    }
+
+    #[test]
+    fn should_wrap_long_imports() {
+        let mut registry = REGISTRY.to_string();
+        for year in (2015..=2025).filter(|&year| year != 2022) {
+            registry = register_year(&registry, year).unwrap();
+        }
+        assert!(registry.starts_with(
+            "use crate::{
+    solution::Puzzle, y2015, y2016, y2017, y2018, y2019, y2020, y2021, y2022, y2023, y2024, y2025,
+};
+"
+        ));
+    }
+
+    #[test]
+    fn should_create_the_module_and_the_inputs() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("src/y2022")).unwrap();
+        fs::create_dir_all(root.join("resources/2022")).unwrap();
+        fs::write(root.join("src/lib.rs"), LIB).unwrap();
+        fs::write(root.join("src/registry.rs"), REGISTRY).unwrap();
+        fs::write(root.join("src/y2022/mod.rs"), YEAR_MODULE).unwrap();
+        fs::write(root.join("resources/2022/day4.txt"), "fetched").unwrap();
+
+        let scaffold = new_day(&root, 2022, 4).unwrap();
+        assert_eq!(scaffold.module, root.join("src/y2022/day_4.rs"));
+        assert_eq!(scaffold.year_module, None);
+        assert_eq!(
+            scaffold.resources,
+            vec![root.join("resources/2022/day4-example.txt")]
+        );
+        assert_eq!(
+            fs::read_to_string(root.join("resources/2022/day4.txt")).unwrap(),
+            "fetched"
+        );
+        let source = fs::read_to_string(&scaffold.module).unwrap();
+        assert!(source.contains("pub struct Day4;"));
+        assert!(source.contains("const YEAR: u16 = 2022;"));
+        assert!(source.contains("const DAY: u8 = 4;"));
+        assert!(source.contains("read_variant(2022, 4, \"example\")"));
+        assert!(fs::read_to_string(root.join("src/y2022/mod.rs"))
+            .unwrap()
+            .contains("&day_4::Day4"));
+
+        assert!(new_day(&root, 2022, 4).is_err());
+        assert!(new_day(&root, 2022, 26).is_err());
+        assert!(new_day(&root, 1999, 1).is_err());
+    }
+
+    #[test]
+    fn should_start_a_new_year() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-year-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("src/y2022")).unwrap();
+        fs::write(root.join("src/lib.rs"), LIB).unwrap();
+        fs::write(root.join("src/registry.rs"), REGISTRY).unwrap();
+        fs::write(root.join("src/y2022/mod.rs"), YEAR_MODULE).unwrap();
+
+        let scaffold = new_day(&root, 2023, 1).unwrap();
+        assert_eq!(scaffold.year_module, Some(root.join("src/y2023/mod.rs")));
+        assert_eq!(
+            fs::read_to_string(root.join("src/y2023/mod.rs")).unwrap(),
+            year_source(2023, 1)
+        );
+        assert!(fs::read_to_string(root.join("src/lib.rs"))
+            .unwrap()
+            .contains("pub mod y2023;"));
+        assert!(fs::read_to_string(root.join("src/registry.rs"))
+            .unwrap()
+            .contains("days: y2023::DAYS,"));
+        assert!(root.join("resources/2023/day1.txt").is_file());
+
+        assert!(new_day(&root, 2023, 2).unwrap().year_module.is_none());
+        assert!(fs::read_to_string(root.join("src/y2023/mod.rs"))
+            .unwrap()
+            .contains("&[&day_1::Day1, &day_2::Day2];"));
+    }
+}
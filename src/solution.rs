@@ -0,0 +1,224 @@
+use std::{any::Any, fmt};
+
+use crate::{
+    error::AocError,
+    generate::{Generated, Rng},
+    normalize::{normalize, restore_location, Normalization},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        return match self {
+            Part::One => 1,
+            Part::Two => 2,
+        };
+    }
+
+    pub fn from_number(number: u8) -> Option<Part> {
+        return match number {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        };
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+/// The answer of a puzzle part, as it would be typed into the website.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{}", number),
+            Answer::Text(text) => write!(f, "{}", text),
+        }
+    }
+}
+
+macro_rules! answer_from_number {
+    ($($number:ty),*) => {
+        $(impl From<$number> for Answer {
+            fn from(value: $number) -> Self {
+                Answer::Number(value as i128)
+            }
+        })*
+    };
+}
+
+answer_from_number!(u32, u64, usize, i32, i64);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+/// A day of the calendar: a typed parse step shared by both parts.
+pub trait Solution {
+    type Input;
+
+    const YEAR: u16;
+
+    const DAY: u8;
+
+    /// Whether the common indentation is removed before parsing. Days where
+    /// leading spaces are meaningful turn it off.
+    const DEDENT: bool = true;
+
+    /// Receives the input already normalized, see [`normalize`].
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError>;
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer, AocError>;
+
+    fn part2(&self, _input: &Self::Input) -> Result<Answer, AocError> {
+        return Err(AocError::NotImplemented {
+            day: Self::DAY,
+            part: Part::Two,
+        });
+    }
+
+    /// A naive solution of `part`, checked against the real one, see
+    /// [`crate::differential`].
+    fn reference(&self, part: Part, _input: &Self::Input) -> Result<Answer, AocError> {
+        return Err(AocError::NotImplemented {
+            day: Self::DAY,
+            part,
+        });
+    }
+
+    /// A random input of about `size` entries, see [`crate::generate`].
+    fn generate(&self, _rng: &mut Rng, _size: usize) -> Option<Generated> {
+        return None;
+    }
+}
+
+/// Object safe view of a [`Solution`], used to loop over the registered days.
+pub trait Puzzle: Sync {
+    fn year(&self) -> u16;
+
+    fn day(&self) -> u8;
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Any + Send>, AocError>;
+
+    fn solve(&self, part: Part, parsed: &dyn Any) -> Result<Answer, AocError>;
+
+    /// Same as [`Puzzle::solve`], with the reference solution.
+    fn solve_reference(&self, part: Part, parsed: &dyn Any) -> Result<Answer, AocError>;
+
+    fn generate(&self, seed: u64, size: usize) -> Option<Generated>;
+
+    fn run(&self, part: Part, input: &str) -> Result<Answer, AocError> {
+        let parsed = self.parse(input)?;
+        return self.solve(part, parsed.as_ref());
+    }
+}
+
+impl<S> Puzzle for S
+where
+    S: Solution + Sync,
+    S::Input: Send + 'static,
+{
+    fn year(&self) -> u16 {
+        return S::YEAR;
+    }
+
+    fn day(&self) -> u8 {
+        return S::DAY;
+    }
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Any + Send>, AocError> {
+        let normalized = normalize(input, Normalization { dedent: S::DEDENT });
+        let parsed =
+            Solution::parse(self, &normalized).map_err(|error| restore_location(error, input))?;
+        return Ok(Box::new(parsed));
+    }
+
+    fn generate(&self, seed: u64, size: usize) -> Option<Generated> {
+        return Solution::generate(self, &mut Rng::new(seed), size);
+    }
+
+    fn solve(&self, part: Part, parsed: &dyn Any) -> Result<Answer, AocError> {
+        let input = downcast::<S>(parsed)?;
+        return match part {
+            Part::One => self.part1(input),
+            Part::Two => self.part2(input),
+        };
+    }
+
+    fn solve_reference(&self, part: Part, parsed: &dyn Any) -> Result<Answer, AocError> {
+        return self.reference(part, downcast::<S>(parsed)?);
+    }
+}
+
+fn downcast<S: Solution>(parsed: &dyn Any) -> Result<&S::Input, AocError>
+where
+    S::Input: 'static,
+{
+    return parsed.downcast_ref::<S::Input>().ok_or_else(|| {
+        AocError::validation(format!(
+            "day {} received an input of the wrong type",
+            S::DAY
+        ))
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::Sum;
+
+    #[test]
+    fn should_run_a_part_through_the_puzzle_view() {
+        assert_eq!(Sum.run(Part::One, "1\n2\n3"), Ok(Answer::Number(6)));
+        assert!(Sum.run(Part::One, "1\na").is_err());
+    }
+
+    #[test]
+    fn should_normalize_the_input_before_parsing() {
+        assert_eq!(
+            Sum.run(Part::One, "  1\r\n  2\r\n  3\r\n\r\n"),
+            Ok(Answer::Number(6))
+        );
+    }
+
+    #[test]
+    fn should_report_unsolved_parts() {
+        assert_eq!(
+            Sum.run(Part::Two, "1"),
+            Err(AocError::NotImplemented {
+                day: 1,
+                part: Part::Two
+            })
+        );
+    }
+
+    #[test]
+    fn should_display_answers() {
+        assert_eq!(Answer::from(-12).to_string(), "-12");
+        assert_eq!(Answer::from("CMZ").to_string(), "CMZ");
+    }
+}
@@ -0,0 +1,215 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    error::AocError,
+    solution::Part,
+    verify::{Status, Verification},
+};
+
+/// Number of days in the calendar.
+pub const CALENDAR_DAYS: u8 = 25;
+
+/// Days shown on each line of the calendar.
+const DAYS_PER_ROW: u8 = 5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Progress {
+    /// The answer matches the accepted one.
+    Verified,
+    /// The part runs, but nothing confirms its answer: none is accepted yet,
+    /// or it disagrees with the accepted one, or the run failed.
+    Unverified,
+    /// The part is still the `NotImplemented` stub.
+    Stubbed,
+    /// The day is not registered.
+    Missing,
+}
+
+impl Progress {
+    pub const ALL: [Progress; 4] = [
+        Progress::Verified,
+        Progress::Unverified,
+        Progress::Stubbed,
+        Progress::Missing,
+    ];
+
+    pub fn of(verification: &Verification) -> Progress {
+        return match (&verification.answer, verification.status()) {
+            (Err(AocError::NotImplemented { .. }), _) => Progress::Stubbed,
+            (_, Status::Pass) => Progress::Verified,
+            _ => Progress::Unverified,
+        };
+    }
+
+    pub fn symbol(self) -> char {
+        return match self {
+            Progress::Verified => '*',
+            Progress::Unverified => '+',
+            Progress::Stubbed => '-',
+            Progress::Missing => '.',
+        };
+    }
+
+    pub fn name(self) -> &'static str {
+        return match self {
+            Progress::Verified => "verified",
+            Progress::Unverified => "unverified",
+            Progress::Stubbed => "stubbed",
+            Progress::Missing => "missing",
+        };
+    }
+}
+
+/// The progress of both parts of every day of the calendar, the parts that
+/// were not verified at all being missing. A part is only verified when it is
+/// for every profile.
+pub fn progress(verifications: &[Verification]) -> Vec<(u8, [Progress; 2])> {
+    let mut known: BTreeMap<(u8, Part), Progress> = BTreeMap::new();
+    for verification in verifications {
+        let progress = Progress::of(verification);
+        known
+            .entry((verification.day, verification.part))
+            .and_modify(|known| {
+                if *known == Progress::Verified {
+                    *known = progress;
+                }
+            })
+            .or_insert(progress);
+    }
+    let part = |day, part| {
+        known
+            .get(&(day, part))
+            .copied()
+            .unwrap_or(Progress::Missing)
+    };
+    return (1..=CALENDAR_DAYS)
+        .map(|day| (day, [part(day, Part::One), part(day, Part::Two)]))
+        .collect();
+}
+
+/// Five days per line, each followed by the symbols of its parts, then a
+/// legend and the number of parts in each state.
+pub fn render(verifications: &[Verification]) -> String {
+    let calendar = progress(verifications);
+    let mut lines: Vec<String> = calendar
+        .chunks(DAYS_PER_ROW as usize)
+        .map(|week| {
+            return week
+                .iter()
+                .map(|(day, [one, two])| format!("{:>2} {}{}", day, one.symbol(), two.symbol()))
+                .collect::<Vec<String>>()
+                .join("   ");
+        })
+        .collect();
+
+    let count = |progress: Progress| {
+        calendar
+            .iter()
+            .flat_map(|(_, parts)| parts)
+            .filter(|&&part| part == progress)
+            .count()
+    };
+    lines.push(String::new());
+    lines.push(
+        Progress::ALL
+            .iter()
+            .map(|progress| format!("{} {}", progress.symbol(), progress.name()))
+            .collect::<Vec<String>>()
+            .join("  "),
+    );
+    lines.push(
+        Progress::ALL
+            .iter()
+            .map(|&progress| format!("{} {}", count(progress), progress.name()))
+            .collect::<Vec<String>>()
+            .join(", "),
+    );
+    return lines.join("\n");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::Answer;
+
+    fn verification(
+        day: u8,
+        part: Part,
+        answer: Result<Answer, AocError>,
+        expected: Option<i32>,
+    ) -> Verification {
+        return Verification {
+            profile: None,
+            day,
+            part,
+            answer,
+            expected: expected.map(Answer::from),
+        };
+    }
+
+    #[test]
+    fn should_tell_the_progress_of_a_part() {
+        let progress =
+            |answer, expected| Progress::of(&verification(1, Part::One, answer, expected));
+        assert_eq!(progress(Ok(3.into()), Some(3)), Progress::Verified);
+        assert_eq!(progress(Ok(3.into()), Some(4)), Progress::Unverified);
+        assert_eq!(progress(Ok(3.into()), None), Progress::Unverified);
+        assert_eq!(
+            progress(Err(AocError::validation("no input")), Some(3)),
+            Progress::Unverified
+        );
+        assert_eq!(
+            progress(
+                Err(AocError::NotImplemented {
+                    day: 1,
+                    part: Part::One
+                }),
+                None
+            ),
+            Progress::Stubbed
+        );
+        assert_eq!(
+            progress(Err(AocError::Panicked("not yet".to_string())), None),
+            Progress::Unverified
+        );
+    }
+
+    #[test]
+    fn should_only_verify_a_part_verified_for_every_profile() {
+        let alice = |answer: i32| Verification {
+            profile: Some("alice".to_string()),
+            ..verification(1, Part::One, Ok(answer.into()), Some(5))
+        };
+        let default = || verification(1, Part::One, Ok(3.into()), Some(3));
+        assert_eq!(progress(&[default(), alice(5)])[0].1[0], Progress::Verified);
+        assert_eq!(
+            progress(&[default(), alice(6)])[0].1[0],
+            Progress::Unverified
+        );
+    }
+
+    #[test]
+    fn should_render_the_calendar() {
+        let stub = Err(AocError::NotImplemented {
+            day: 2,
+            part: Part::Two,
+        });
+        let verifications = vec![
+            verification(1, Part::One, Ok(3.into()), Some(3)),
+            verification(1, Part::Two, Ok(5.into()), None),
+            verification(2, Part::One, Ok(7.into()), Some(7)),
+            verification(2, Part::Two, stub, None),
+        ];
+        assert_eq!(
+            render(&verifications),
+            " 1 *+    2 *-    3 ..    4 ..    5 ..
+ 6 ..    7 ..    8 ..    9 ..   10 ..
+11 ..   12 ..   13 ..   14 ..   15 ..
+16 ..   17 ..   18 ..   19 ..   20 ..
+21 ..   22 ..   23 ..   24 ..   25 ..
+
+* verified  + unverified  - stubbed  . missing
+2 verified, 1 unverified, 1 stubbed, 46 missing"
+        );
+    }
+}
@@ -0,0 +1,359 @@
+use std::{fmt, fs, path::Path, time::Duration};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{error::AocError, solution::Part, website::Client};
+
+/// Every answer sent to the website, with what it answered.
+pub const SUBMISSIONS_FILE: &str = "submissions.toml";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint of the direction.
+    Wrong,
+    /// Answered too soon after a previous one; nothing was checked.
+    RateLimited {
+        wait: Duration,
+    },
+    /// The part was already solved, or is not unlocked yet.
+    AlreadySolved,
+}
+
+impl Verdict {
+    fn name(&self) -> &'static str {
+        return match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too high",
+            Verdict::TooLow => "too low",
+            Verdict::Wrong => "wrong",
+            Verdict::RateLimited { .. } => "rate limited",
+            Verdict::AlreadySolved => "already solved",
+        };
+    }
+
+    fn from_name(name: &str, wait: Option<u64>) -> Option<Verdict> {
+        return match name {
+            "correct" => Some(Verdict::Correct),
+            "too high" => Some(Verdict::TooHigh),
+            "too low" => Some(Verdict::TooLow),
+            "wrong" => Some(Verdict::Wrong),
+            "rate limited" => Some(Verdict::RateLimited {
+                wait: Duration::from_secs(wait.unwrap_or(0)),
+            }),
+            "already solved" => Some(Verdict::AlreadySolved),
+            _ => None,
+        };
+    }
+
+    pub fn is_wrong(&self) -> bool {
+        return matches!(self, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong);
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::RateLimited { wait } => {
+                write!(f, "rate limited, wait {}s", wait.as_secs())
+            }
+            verdict => write!(f, "{}", verdict.name()),
+        }
+    }
+}
+
+/// `You have 1m 5s left to wait` or `You have 34s left to wait`.
+fn parse_wait(page: &str) -> Option<Duration> {
+    let start = page.find("You have ")? + "You have ".len();
+    let end = start + page[start..].find(" left to wait")?;
+    let mut seconds = 0;
+    for amount in page[start..end].split_whitespace() {
+        let (number, unit) = amount.split_at(amount.find(|c: char| !c.is_ascii_digit())?);
+        let number = number.parse::<u64>().ok()?;
+        seconds += match unit {
+            "h" => number * 3600,
+            "m" => number * 60,
+            "s" => number,
+            _ => return None,
+        };
+    }
+    return Some(Duration::from_secs(seconds));
+}
+
+/// Reads the verdict out of the page answered to a submission.
+pub fn classify(page: &str) -> Result<Verdict, AocError> {
+    if page.contains("That's the right answer") {
+        return Ok(Verdict::Correct);
+    }
+    if page.contains("That's not the right answer") {
+        return Ok(if page.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if page.contains("your answer is too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Wrong
+        });
+    }
+    if page.contains("You gave an answer too recently") {
+        return parse_wait(page)
+            .map(|wait| Verdict::RateLimited { wait })
+            .ok_or_else(|| AocError::Http("unable to read the time left to wait".to_string()));
+    }
+    if page.contains("You don't seem to be solving the right level") {
+        return Ok(Verdict::AlreadySolved);
+    }
+    return Err(AocError::Http(
+        "unable to understand the answer of the website".to_string(),
+    ));
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Submission {
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: String,
+    verdict: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    wait_secs: Option<u64>,
+    /// Seconds since the Unix epoch.
+    at: u64,
+}
+
+impl Submission {
+    fn verdict(&self) -> Option<Verdict> {
+        return Verdict::from_name(&self.verdict, self.wait_secs);
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct SubmissionLog {
+    #[serde(default, rename = "submission")]
+    submissions: Vec<Submission>,
+}
+
+impl SubmissionLog {
+    /// A missing file is an empty log.
+    pub fn load(path: &Path) -> Result<SubmissionLog, AocError> {
+        if !path.exists() {
+            return Ok(SubmissionLog::default());
+        }
+        let content = fs::read_to_string(path)
+            .map_err(|e| AocError::Io(format!("unable to read {}: {}", path.display(), e)))?;
+        return toml::from_str(&content).map_err(|e| AocError::parse(e.to_string()));
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), AocError> {
+        let content = toml::to_string(self).map_err(|e| AocError::Io(e.to_string()))?;
+        return fs::write(path, content)
+            .map_err(|e| AocError::Io(format!("unable to write {}: {}", path.display(), e)));
+    }
+
+    pub fn record(
+        &mut self,
+        year: u16,
+        day: u8,
+        part: Part,
+        answer: &str,
+        verdict: Verdict,
+        at: u64,
+    ) {
+        self.submissions.push(Submission {
+            year,
+            day,
+            part: part.number(),
+            answer: answer.to_string(),
+            verdict: verdict.name().to_string(),
+            wait_secs: match verdict {
+                Verdict::RateLimited { wait } => Some(wait.as_secs()),
+                _ => None,
+            },
+            at,
+        });
+    }
+
+    fn of_part(&self, year: u16, day: u8, part: Part) -> impl Iterator<Item = &Submission> {
+        return self
+            .submissions
+            .iter()
+            .filter(move |s| s.year == year && s.day == day && s.part == part.number());
+    }
+
+    /// Fails when the answer cannot be right given the previous verdicts, or
+    /// when the website asked to wait and the wait is not over at `now`. The
+    /// wait applies to every year, as the website does.
+    pub fn check(
+        &self,
+        year: u16,
+        day: u8,
+        part: Part,
+        answer: &str,
+        now: u64,
+    ) -> Result<(), AocError> {
+        if let Some(wait_until) = self
+            .submissions
+            .iter()
+            .filter_map(|s| match s.verdict() {
+                Some(Verdict::RateLimited { wait }) => Some(s.at + wait.as_secs()),
+                _ => None,
+            })
+            .max()
+        {
+            if now < wait_until {
+                return Err(AocError::Refused(format!(
+                    "the website asked to wait {}s more",
+                    wait_until - now
+                )));
+            }
+        }
+
+        let number = answer.parse::<i128>().ok();
+        for submission in self.of_part(year, day, part) {
+            let previous = submission.answer.parse::<i128>().ok();
+            let refusal = match (submission.verdict(), number, previous) {
+                (Some(Verdict::Correct), _, _) => {
+                    Some(format!("{} was already accepted", submission.answer))
+                }
+                (Some(verdict), _, _) if verdict.is_wrong() && submission.answer == answer => {
+                    Some(format!("{} is known to be {}", answer, verdict))
+                }
+                (Some(Verdict::TooHigh), Some(number), Some(previous)) if number >= previous => {
+                    Some(format!("{} was already too high", previous))
+                }
+                (Some(Verdict::TooLow), Some(number), Some(previous)) if number <= previous => {
+                    Some(format!("{} was already too low", previous))
+                }
+                _ => None,
+            };
+            if let Some(refusal) = refusal {
+                return Err(AocError::Refused(refusal));
+            }
+        }
+        return Ok(());
+    }
+}
+
+/// Sends the answer unless the log already rules it out, then records the verdict.
+pub fn submit(
+    client: &Client,
+    log: &mut SubmissionLog,
+    year: u16,
+    day: u8,
+    part: Part,
+    answer: &str,
+    now: u64,
+) -> Result<Verdict, AocError> {
+    log.check(year, day, part, answer, now)?;
+    let verdict = classify(&client.post_answer(year, day, part, answer)?)?;
+    log.record(year, day, part, answer, verdict, now);
+    return Ok(verdict);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http_stub::StubServer;
+
+    const WRONG: &str = "<article><p>That's not the right answer; your answer is too high. \
+        If you're stuck, make sure you're using the full input data.</p></article>";
+
+    #[test]
+    fn should_classify_the_answer_pages() {
+        assert_eq!(
+            classify("<p>That's the right answer! You are one gold star closer.</p>"),
+            Ok(Verdict::Correct)
+        );
+        assert_eq!(classify(WRONG), Ok(Verdict::TooHigh));
+        assert_eq!(
+            classify("That's not the right answer; your answer is too low."),
+            Ok(Verdict::TooLow)
+        );
+        assert_eq!(
+            classify("That's not the right answer. If you're stuck..."),
+            Ok(Verdict::Wrong)
+        );
+        assert_eq!(
+            classify("You gave an answer too recently. You have 1m 5s left to wait."),
+            Ok(Verdict::RateLimited {
+                wait: Duration::from_secs(65)
+            })
+        );
+        assert_eq!(
+            classify("You don't seem to be solving the right level."),
+            Ok(Verdict::AlreadySolved)
+        );
+        assert!(classify("<html>maintenance</html>").is_err());
+    }
+
+    #[test]
+    fn should_refuse_answers_known_to_be_wrong() {
+        let mut log = SubmissionLog::default();
+        log.record(2022, 7, Part::One, "100", Verdict::TooHigh, 0);
+        log.record(2022, 7, Part::One, "10", Verdict::TooLow, 0);
+        log.record(2022, 7, Part::Two, "ABC", Verdict::Wrong, 0);
+
+        assert!(log.check(2022, 7, Part::One, "100", 1000).is_err());
+        assert!(log.check(2022, 7, Part::One, "150", 1000).is_err());
+        assert!(log.check(2022, 7, Part::One, "5", 1000).is_err());
+        assert!(log.check(2022, 7, Part::One, "50", 1000).is_ok());
+        assert!(log.check(2022, 7, Part::Two, "ABC", 1000).is_err());
+        assert!(log.check(2022, 7, Part::Two, "ABD", 1000).is_ok());
+        assert!(log.check(2022, 8, Part::One, "100", 1000).is_ok());
+        assert!(log.check(2021, 7, Part::One, "100", 1000).is_ok());
+    }
+
+    #[test]
+    fn should_wait_after_being_rate_limited() {
+        let mut log = SubmissionLog::default();
+        let wait = Duration::from_secs(60);
+        log.record(
+            2022,
+            7,
+            Part::One,
+            "42",
+            Verdict::RateLimited { wait },
+            1000,
+        );
+
+        assert!(log.check(2022, 7, Part::One, "43", 1059).is_err());
+        assert!(log.check(2021, 1, Part::One, "43", 1059).is_err());
+        assert!(log.check(2022, 7, Part::One, "43", 1060).is_ok());
+    }
+
+    #[test]
+    fn should_save_and_load_the_log() {
+        let mut log = SubmissionLog::default();
+        log.record(2022, 7, Part::One, "42", Verdict::Correct, 10);
+        let wait = Duration::from_secs(30);
+        log.record(2022, 7, Part::Two, "43", Verdict::RateLimited { wait }, 20);
+
+        let path =
+            std::env::temp_dir().join(format!("aoc-submissions-{}.toml", std::process::id()));
+        log.save(&path).unwrap();
+        assert_eq!(SubmissionLog::load(&path), Ok(log));
+    }
+
+    #[test]
+    fn should_post_the_answer_and_record_the_verdict() {
+        let server = StubServer::start(|_| (200, WRONG.to_string()));
+        let client = Client::new(server.base_url.clone(), Some("abc".to_string()));
+        let mut log = SubmissionLog::default();
+
+        assert_eq!(
+            submit(&client, &mut log, 2022, 7, Part::Two, "1234", 0),
+            Ok(Verdict::TooHigh)
+        );
+        assert!(matches!(
+            submit(&client, &mut log, 2022, 7, Part::Two, "1234", 0),
+            Err(AocError::Refused(_))
+        ));
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].path, "/2022/day/7/answer");
+        assert_eq!(requests[0].body, "level=2&answer=1234");
+    }
+}
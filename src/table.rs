@@ -0,0 +1,46 @@
+/// Renders rows as left aligned columns separated by two spaces.
+pub fn render(headers: &[&str], rows: &[Vec<String>]) -> String {
+    let mut widths: Vec<usize> = headers.iter().map(|header| header.len()).collect();
+    for row in rows {
+        for (idx, cell) in row.iter().enumerate() {
+            if idx < widths.len() {
+                widths[idx] = widths[idx].max(cell.chars().count());
+            } else {
+                widths.push(cell.chars().count());
+            }
+        }
+    }
+
+    let format_row = |cells: Vec<&str>| -> String {
+        let line = cells
+            .iter()
+            .enumerate()
+            .map(|(idx, cell)| format!("{:width$}", cell, width = widths[idx]))
+            .collect::<Vec<String>>()
+            .join("  ");
+        return line.trim_end().to_string();
+    };
+
+    let mut lines = vec![format_row(headers.to_vec())];
+    for row in rows {
+        lines.push(format_row(row.iter().map(|cell| cell.as_str()).collect()));
+    }
+    return lines.join("\n");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_align_columns() {
+        let rows = vec![
+            vec!["1".to_string(), "67633".to_string()],
+            vec!["10".to_string(), "11220".to_string()],
+        ];
+        assert_eq!(
+            render(&["Day", "Answer"], &rows),
+            "Day  Answer\n1    67633\n10   11220"
+        );
+    }
+}
@@ -0,0 +1,99 @@
+use std::{
+    env, fmt,
+    sync::atomic::{AtomicU8, Ordering},
+};
+
+/// Turns tracing on when set to `info` or `debug`.
+pub const TRACE_VARIABLE: &str = "AOC_TRACE";
+
+/// How much of the reasoning of a day is shown. `Info` traces the main steps,
+/// `Debug` every step of the inner loops.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Info = 1,
+    Debug = 2,
+}
+
+impl Level {
+    pub fn from_name(name: &str) -> Option<Level> {
+        return match name.to_ascii_lowercase().as_str() {
+            "info" => Some(Level::Info),
+            "debug" | "1" | "true" => Some(Level::Debug),
+            _ => None,
+        };
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Level::Info => write!(f, "info"),
+            Level::Debug => write!(f, "debug"),
+        }
+    }
+}
+
+static LEVEL: AtomicU8 = AtomicU8::new(0);
+
+/// Shows the events up to `level`, or none.
+pub fn set_level(level: Option<Level>) {
+    LEVEL.store(level.map_or(0, |level| level as u8), Ordering::Relaxed);
+}
+
+/// Reads the level from `AOC_TRACE`, leaving tracing off when it is unset.
+pub fn init_from_env() {
+    let level = env::var(TRACE_VARIABLE)
+        .ok()
+        .and_then(|value| Level::from_name(&value));
+    set_level(level);
+}
+
+pub fn enabled(level: Level) -> bool {
+    return LEVEL.load(Ordering::Relaxed) >= level as u8;
+}
+
+/// `aoc::y2022::day_8` becomes `day_8`.
+pub fn format_event(level: Level, module: &str, message: fmt::Arguments) -> String {
+    let source = module.rsplit("::").next().unwrap_or(module);
+    return format!("[{} {}] {}", level, source, message);
+}
+
+pub fn emit(level: Level, module: &str, message: fmt::Arguments) {
+    eprintln!("{}", format_event(level, module, message));
+}
+
+/// Emits a step event on stderr when its level is enabled. The arguments are
+/// not evaluated otherwise, so events are cheap to leave in hot loops.
+#[macro_export]
+macro_rules! trace {
+    ($level:expr, $($arg:tt)*) => {
+        if $crate::trace::enabled($level) {
+            $crate::trace::emit($level, module_path!(), format_args!($($arg)*));
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_levels() {
+        assert_eq!(Level::from_name("info"), Some(Level::Info));
+        assert_eq!(Level::from_name("DEBUG"), Some(Level::Debug));
+        assert_eq!(Level::from_name("1"), Some(Level::Debug));
+        assert_eq!(Level::from_name("loud"), None);
+    }
+
+    #[test]
+    fn should_format_events_with_their_day() {
+        assert_eq!(
+            format_event(
+                Level::Debug,
+                "aoc::y2022::day_8",
+                format_args!("visiting {:?}", (1, 2))
+            ),
+            "[debug day_8] visiting (1, 2)"
+        );
+    }
+}
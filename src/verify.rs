@@ -0,0 +1,232 @@
+use std::fmt;
+
+use crate::{
+    answers::Answers,
+    error::AocError,
+    input::InputProvider,
+    runner,
+    solution::{Answer, Part, Puzzle},
+    table,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail,
+    /// No accepted answer is recorded for the part.
+    Missing,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Pass => write!(f, "pass"),
+            Status::Fail => write!(f, "fail"),
+            Status::Missing => write!(f, "missing"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Verification {
+    /// `None` for the inputs of the account the tools run for.
+    pub profile: Option<String>,
+    pub day: u8,
+    pub part: Part,
+    pub answer: Result<Answer, AocError>,
+    pub expected: Option<Answer>,
+}
+
+impl Verification {
+    pub fn status(&self) -> Status {
+        return match (&self.answer, &self.expected) {
+            (_, None) => Status::Missing,
+            (Ok(answer), Some(expected)) if answer.to_string() == expected.to_string() => {
+                Status::Pass
+            }
+            (_, Some(_)) => Status::Fail,
+        };
+    }
+}
+
+/// Runs both parts of every puzzle on its input, then on the input of every
+/// profile `inputs` finds, and compares with the accepted answers. Profiles
+/// without an input for a day are left out of that day.
+pub fn verify(
+    puzzles: &[&'static dyn Puzzle],
+    inputs: &InputProvider,
+    answers: &Answers,
+) -> Vec<Verification> {
+    let profiles: Vec<InputProvider> = std::iter::once(None)
+        .chain(inputs.profiles().into_iter().map(Some))
+        .map(|profile| inputs.clone().with_profile(profile))
+        .collect();
+    let mut verifications = Vec::new();
+    for puzzle in puzzles {
+        for inputs in &profiles {
+            let profile = inputs.profile();
+            let results = runner::run_puzzle(*puzzle, &Part::ALL, inputs, runner::DEFAULT_TIMEOUT);
+            verifications.extend(
+                results
+                    .into_iter()
+                    .filter(|result| {
+                        profile.is_none()
+                            || !matches!(result.answer, Err(AocError::MissingInput { .. }))
+                    })
+                    .map(|result| Verification {
+                        profile: profile.map(str::to_string),
+                        day: result.day,
+                        part: result.part,
+                        expected: answers
+                            .get(profile, puzzle.year(), result.day, result.part)
+                            .cloned(),
+                        answer: result.answer,
+                    }),
+            );
+        }
+    }
+    return verifications;
+}
+
+pub fn render(verifications: &[Verification]) -> String {
+    let rows: Vec<Vec<String>> = verifications
+        .iter()
+        .map(|verification| {
+            vec![
+                verification.day.to_string(),
+                verification.part.to_string(),
+                verification
+                    .profile
+                    .clone()
+                    .unwrap_or_else(|| "-".to_string()),
+                verification.status().to_string(),
+                match &verification.answer {
+                    Ok(answer) => answer.to_string(),
+                    Err(error) => error.to_string(),
+                },
+                verification
+                    .expected
+                    .as_ref()
+                    .map_or_else(|| "-".to_string(), |expected| expected.to_string()),
+            ]
+        })
+        .collect();
+
+    let count = |status: Status| {
+        verifications
+            .iter()
+            .filter(|verification| verification.status() == status)
+            .count()
+    };
+
+    return format!(
+        "{}\n\n{} passed, {} failed, {} missing",
+        table::render(
+            &["Day", "Part", "Profile", "Status", "Answer", "Expected"],
+            &rows
+        ),
+        count(Status::Pass),
+        count(Status::Fail),
+        count(Status::Missing)
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::Sum;
+
+    fn temp_dir(name: &str) -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-verify-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        return dir;
+    }
+
+    fn verify_sum(name: &str, input: &str, answers: &str) -> Vec<Status> {
+        let dir = temp_dir(name);
+        let path = dir.join("input.txt");
+        std::fs::write(&path, input).unwrap();
+
+        let inputs = InputProvider::new()
+            .with_resources_dir(&dir)
+            .with_path(path);
+        let answers = Answers::parse(answers).unwrap();
+        return verify(&[&Sum], &inputs, &answers)
+            .iter()
+            .map(|verification| verification.status())
+            .collect();
+    }
+
+    #[test]
+    fn should_pass_fail_or_miss() {
+        assert_eq!(
+            verify_sum("pass", "1\n2", "[2022.day1]\npart1 = 3"),
+            vec![Status::Pass, Status::Missing]
+        );
+        assert_eq!(
+            verify_sum("fail", "1\n2", "[2022.day1]\npart1 = 4\npart2 = 1"),
+            vec![Status::Fail, Status::Fail]
+        );
+    }
+
+    #[test]
+    fn should_fail_when_the_input_does_not_parse() {
+        assert_eq!(
+            verify_sum("parse", "1\ntwo", "[2022.day1]\npart1 = 3"),
+            vec![Status::Fail, Status::Missing]
+        );
+    }
+
+    #[test]
+    fn should_verify_every_profile() {
+        let dir = temp_dir("profiles");
+        for (path, input) in [
+            ("2022/day1.txt", "1\n2"),
+            ("alice/2022/day1.txt", "5"),
+            ("bob/2021/day1.txt", "7"),
+        ] {
+            std::fs::create_dir_all(dir.join(path).parent().unwrap()).unwrap();
+            std::fs::write(dir.join(path), input).unwrap();
+        }
+        let inputs = InputProvider::new().with_resources_dir(&dir);
+        let answers =
+            Answers::parse("[2022.day1]\npart1 = 3\n\n[alice.2022.day1]\npart1 = 6").unwrap();
+
+        let verified: Vec<(Option<String>, Part, Status)> = verify(&[&Sum], &inputs, &answers)
+            .iter()
+            .map(|verification| {
+                (
+                    verification.profile.clone(),
+                    verification.part,
+                    verification.status(),
+                )
+            })
+            .collect();
+        let alice = Some("alice".to_string());
+        assert_eq!(
+            verified,
+            vec![
+                (None, Part::One, Status::Pass),
+                (None, Part::Two, Status::Missing),
+                (alice.clone(), Part::One, Status::Fail),
+                (alice, Part::Two, Status::Missing),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_render_a_summary() {
+        let verifications = vec![Verification {
+            profile: None,
+            day: 1,
+            part: Part::One,
+            answer: Ok(Answer::from(3)),
+            expected: Some(Answer::from(3)),
+        }];
+        assert_eq!(
+            render(&verifications),
+            "Day  Part  Profile  Status  Answer  Expected\n1    1     -        pass    3       3\n\n1 passed, 0 failed, 0 missing"
+        );
+    }
+}
@@ -0,0 +1,267 @@
+use std::{env, fs, io::Read, path::PathBuf, time::Duration};
+
+use crate::{
+    error::AocError,
+    input::{relative_path, InputProvider},
+    solution::Part,
+};
+
+pub const SESSION_VARIABLE: &str = "AOC_SESSION";
+
+pub const BASE_URL_VARIABLE: &str = "AOC_BASE_URL";
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Identifies the tool to the website, as its maintainers ask automated clients to.
+pub const USER_AGENT: &str = concat!(
+    "github.com/Dnomyar/advent-of-code-22 aoc/",
+    env!("CARGO_PKG_VERSION")
+);
+
+/// Talks to the puzzle website, or to whatever serves the same paths at `base_url`.
+pub struct Client {
+    base_url: String,
+    session: Option<String>,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: impl Into<String>, session: Option<String>) -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(USER_AGENT)
+            .timeout(Duration::from_secs(30))
+            .build();
+        return Client {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            session,
+            agent,
+        };
+    }
+
+    /// Reads the session token from `AOC_SESSION` and the base URL from
+    /// `AOC_BASE_URL` unless one is given.
+    pub fn from_env(base_url: Option<String>) -> Self {
+        let base_url = base_url
+            .or_else(|| env::var(BASE_URL_VARIABLE).ok())
+            .unwrap_or_else(|| DEFAULT_BASE_URL.to_string());
+        let session = env::var(SESSION_VARIABLE)
+            .ok()
+            .map(|session| session.trim().to_string())
+            .filter(|session| !session.is_empty());
+        return Client::new(base_url, session);
+    }
+
+    pub fn day_url(&self, year: u16, day: u8) -> String {
+        return format!("{}/{}/day/{}", self.base_url, year, day);
+    }
+
+    fn session_cookie(&self) -> Result<String, AocError> {
+        return self
+            .session
+            .as_ref()
+            .map(|session| format!("session={}", session))
+            .ok_or_else(|| AocError::Http(format!("{} is not set", SESSION_VARIABLE)));
+    }
+
+    /// Sends the request with the session cookie. A 404 means the day is not unlocked.
+    fn send(
+        &self,
+        day: u8,
+        request: ureq::Request,
+        form: Option<&[(&str, &str)]>,
+    ) -> Result<String, AocError> {
+        let request = request.set("Cookie", &self.session_cookie()?);
+        let response = match form {
+            Some(form) => request.send_form(form),
+            None => request.call(),
+        };
+        let response = match response {
+            Ok(response) => response,
+            Err(ureq::Error::Status(404, _)) => return Err(AocError::NotUnlocked { day }),
+            Err(ureq::Error::Status(status @ (400 | 401 | 403), _)) => {
+                return Err(AocError::Http(format!(
+                    "the session token was rejected ({})",
+                    status
+                )))
+            }
+            Err(ureq::Error::Status(status, _)) => {
+                return Err(AocError::Http(format!("unexpected status {}", status)))
+            }
+            Err(error) => return Err(AocError::Http(error.to_string())),
+        };
+
+        let mut body = String::new();
+        response
+            .into_reader()
+            .read_to_string(&mut body)
+            .map_err(|e| AocError::Http(e.to_string()))?;
+        return Ok(body);
+    }
+
+    pub fn download_input(&self, year: u16, day: u8) -> Result<String, AocError> {
+        let request = self
+            .agent
+            .get(&format!("{}/input", self.day_url(year, day)));
+        return self.send(day, request, None);
+    }
+
+    /// Posts an answer and returns the page the website answered with.
+    pub fn post_answer(
+        &self,
+        year: u16,
+        day: u8,
+        part: Part,
+        answer: &str,
+    ) -> Result<String, AocError> {
+        let request = self
+            .agent
+            .post(&format!("{}/answer", self.day_url(year, day)));
+        let level = part.to_string();
+        return self.send(
+            day,
+            request,
+            Some(&[("level", level.as_str()), ("answer", answer)]),
+        );
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Fetched {
+    /// The input was already in the cache, the website was not contacted.
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+/// Downloads the input of a day into the directory of its year in the cache
+/// directory of `inputs`, unless `inputs` already has it in any of the
+/// directories it reads from.
+pub fn fetch_input(
+    client: &Client,
+    inputs: &InputProvider,
+    year: u16,
+    day: u8,
+) -> Result<Fetched, AocError> {
+    if let Some(path) = inputs.locate(year, day, None) {
+        return Ok(Fetched::Cached(path));
+    }
+
+    let input = client.download_input(year, day)?;
+    let cache_dir = inputs.cache_dir();
+    let path = cache_dir.join(relative_path(year, day, None));
+    let year_dir = path.parent().unwrap_or(&cache_dir);
+    fs::create_dir_all(year_dir)
+        .map_err(|e| AocError::Io(format!("unable to create {}: {}", year_dir.display(), e)))?;
+    fs::write(&path, input)
+        .map_err(|e| AocError::Io(format!("unable to write {}: {}", path.display(), e)))?;
+    return Ok(Fetched::Downloaded(path));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http_stub::StubServer;
+
+    /// Inputs read from, and downloaded into, the `input` directory of a
+    /// fresh temporary directory, with its `resources` directory as fallback.
+    fn inputs(name: &str) -> (InputProvider, PathBuf) {
+        let dir = env::temp_dir().join(format!("aoc-fetch-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let inputs = InputProvider::new()
+            .with_input_dir(dir.join("input"))
+            .with_resources_dir(dir.join("resources"));
+        return (inputs, dir);
+    }
+
+    fn website() -> StubServer {
+        return StubServer::start(|request| match request.path.as_str() {
+            "/2022/day/1/input" => (200, "1000\n2000\n".to_string()),
+            "/2021/day/1/input" => (200, "199\n200\n".to_string()),
+            _ => (
+                404,
+                "Please don't repeatedly request this endpoint".to_string(),
+            ),
+        });
+    }
+
+    #[test]
+    fn should_download_then_reuse_the_cache() {
+        let server = website();
+        let client = Client::new(server.base_url.clone(), Some("abc".to_string()));
+        let (inputs, dir) = inputs("cache");
+        let path = dir.join("input/2022/day1.txt");
+
+        assert_eq!(
+            fetch_input(&client, &inputs, 2022, 1),
+            Ok(Fetched::Downloaded(path.clone()))
+        );
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1000\n2000\n");
+        assert_eq!(
+            fetch_input(&client, &inputs, 2022, 1),
+            Ok(Fetched::Cached(path))
+        );
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].header("Cookie"), Some("session=abc"));
+        assert_eq!(requests[0].header("User-Agent"), Some(USER_AGENT));
+    }
+
+    #[test]
+    fn should_reuse_an_input_of_the_resources() {
+        let server = website();
+        let client = Client::new(server.base_url.clone(), Some("abc".to_string()));
+        let (inputs, dir) = inputs("resources");
+        let path = dir.join("resources/2022/day1.txt");
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, "1000\n").unwrap();
+
+        assert_eq!(
+            fetch_input(&client, &inputs, 2022, 1),
+            Ok(Fetched::Cached(path))
+        );
+        assert!(!dir.join("input/2022/day1.txt").exists());
+        assert!(server.requests().is_empty());
+    }
+
+    #[test]
+    fn should_keep_the_years_apart() {
+        let server = website();
+        let client = Client::new(server.base_url.clone(), Some("abc".to_string()));
+        let (inputs, dir) = inputs("years");
+
+        assert_eq!(
+            fetch_input(&client, &inputs, 2021, 1),
+            Ok(Fetched::Downloaded(dir.join("input/2021/day1.txt")))
+        );
+        assert_eq!(
+            fs::read_to_string(dir.join("input/2021/day1.txt")).unwrap(),
+            "199\n200\n"
+        );
+        assert!(!dir.join("input/2022/day1.txt").exists());
+    }
+
+    #[test]
+    fn should_report_locked_days() {
+        let server = website();
+        let client = Client::new(server.base_url.clone(), Some("abc".to_string()));
+        let (inputs, dir) = inputs("locked");
+
+        assert_eq!(
+            fetch_input(&client, &inputs, 2022, 25),
+            Err(AocError::NotUnlocked { day: 25 })
+        );
+        assert!(!dir.join("input/2022/day25.txt").exists());
+    }
+
+    #[test]
+    fn should_require_a_session() {
+        let server = website();
+        let client = Client::new(server.base_url.clone(), None);
+        assert!(matches!(
+            fetch_input(&client, &inputs("session").0, 2022, 1),
+            Err(AocError::Http(_))
+        ));
+        assert!(server.requests().is_empty());
+    }
+}
@@ -0,0 +1,177 @@
+use std::{cmp::Reverse, str};
+
+use itertools::Itertools;
+use priority_queue::PriorityQueue;
+
+use crate::{
+    error::AocError,
+    generate::{Generated, Rng},
+    parsing::{blocks, lines, parse_all, unsigned},
+    solution::{Answer, Part, Solution},
+    trace,
+    trace::Level,
+};
+
+/// One group of calories per elf, groups being separated by a blank line.
+pub fn parse_elves_groups(rawData: &str) -> Result<Vec<Vec<u32>>, AocError> {
+    let elves = parse_all(blocks(lines(unsigned::<u32>)), rawData)?;
+    trace!(Level::Info, "{} elves", elves.len());
+    return Ok(elves);
+}
+
+/// Calories carried by the elf carrying the most.
+pub fn part1(elves: &[Vec<u32>]) -> Option<u32> {
+    return elves
+        .iter()
+        .map(|calory_group| calory_group.iter().sum())
+        .max();
+}
+
+/// Calories carried by the three elves carrying the most.
+pub fn part2(elves: &[Vec<u32>]) -> Option<u32> {
+    let numberOfElvesToSum: usize = 3;
+    // keyed by elf, so that elves carrying as much are all kept, the one
+    // carrying the least being on top
+    let mut pq: PriorityQueue<usize, Reverse<u32>> = PriorityQueue::new();
+
+    let group_summed = elves
+        .iter()
+        .map(|calory_group| calory_group.iter().sum::<u32>());
+
+    group_summed.enumerate().for_each(|(elf, calory_sum)| {
+        let should_update_max = match pq.peek() {
+            Some((_, Reverse(smallest_kept))) if pq.len() >= numberOfElvesToSum => {
+                smallest_kept < &calory_sum
+            }
+            _ => true,
+        };
+
+        if should_update_max {
+            if pq.len() >= numberOfElvesToSum {
+                pq.pop();
+            }
+
+            pq.push(elf, Reverse(calory_sum));
+            trace!(Level::Debug, "keeping {} among the top elves", calory_sum);
+        }
+    });
+
+    return Some(pq.into_iter().map(|(_, Reverse(sum))| sum).sum::<u32>());
+}
+
+/// `size` elves carrying 1 to 10 snacks each.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let elves: Vec<Vec<u32>> = (0..size.max(1))
+        .map(|_| {
+            let snacks = rng.range(1, 10);
+            return (0..snacks).map(|_| rng.range(1000, 60000) as u32).collect();
+        })
+        .collect();
+    let mut totals: Vec<u32> = elves.iter().map(|elf| elf.iter().sum()).collect();
+    totals.sort_unstable_by(|a, b| b.cmp(a));
+    let input = elves
+        .iter()
+        .map(|elf| elf.iter().map(|calories| calories.to_string()).join("\n"))
+        .join("\n\n");
+    return Generated {
+        input,
+        part1: Some(totals[0].into()),
+        part2: Some(totals.iter().take(3).sum::<u32>().into()),
+    };
+}
+
+/// Calories carried by the `top` elves carrying the most, sorting every elf.
+fn reference_top(elves: &[Vec<u32>], top: usize) -> Option<u32> {
+    if elves.is_empty() {
+        return None;
+    }
+    let mut totals: Vec<u32> = elves.iter().map(|elf| elf.iter().sum()).collect();
+    totals.sort_unstable();
+    return Some(totals.iter().rev().take(top).sum());
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<Vec<u32>>;
+
+    const YEAR: u16 = 2022;
+
+    const DAY: u8 = 1;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        return parse_elves_groups(input);
+    }
+
+    fn part1(&self, elves: &Self::Input) -> Result<Answer, AocError> {
+        return part1(elves)
+            .map(Answer::from)
+            .ok_or_else(|| AocError::validation("no elves found"));
+    }
+
+    fn part2(&self, elves: &Self::Input) -> Result<Answer, AocError> {
+        return part2(elves)
+            .map(Answer::from)
+            .ok_or_else(|| AocError::validation("no elves found"));
+    }
+
+    fn reference(&self, part: Part, elves: &Self::Input) -> Result<Answer, AocError> {
+        let top = match part {
+            Part::One => 1,
+            Part::Two => 3,
+        };
+        return reference_top(elves, top)
+            .map(Answer::from)
+            .ok_or_else(|| AocError::validation("no elves found"));
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        return Some(generate(rng, size));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answers::known_answer;
+    use crate::input::read_input;
+    use crate::solution::{Part, Puzzle};
+
+    #[test]
+    fn dayone_part1_test() {
+        let input = read_input(2022, 1).unwrap();
+        assert_eq!(
+            Day1.run(Part::One, &input),
+            Ok(known_answer(2022, 1, Part::One))
+        );
+    }
+
+    #[test]
+    fn should_accept_windows_line_endings() {
+        let input = "1000\r\n2000\r\n\r\n4000\r\n";
+        assert_eq!(Day1.run(Part::One, input), Ok(4000.into()));
+    }
+
+    #[test]
+    fn should_reject_invalid_calories() {
+        assert!(matches!(
+            Day1.run(Part::One, "100\n\nlots"),
+            Err(AocError::Parse(_))
+        ));
+        assert_eq!(
+            Day1.run(Part::One, "100\n\n20\nlots")
+                .unwrap_err()
+                .to_string(),
+            "parse error at line 4, column 1: expected a number, found \"lots\""
+        );
+    }
+
+    #[test]
+    fn dayone_part2_test() {
+        let input = read_input(2022, 1).unwrap();
+        assert_eq!(
+            Day1.run(Part::Two, &input),
+            Ok(known_answer(2022, 1, Part::Two))
+        );
+    }
+}
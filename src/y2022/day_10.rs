@@ -0,0 +1,243 @@
+use std::collections::{HashMap, VecDeque};
+
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    combinator::{map, value},
+    error::context,
+};
+
+use crate::{
+    error::AocError,
+    generate::{Generated, Rng},
+    parsing::{keyword_value, lines, parse_all, signed, PResult},
+    solution::{Answer, Part, Solution},
+    trace,
+    trace::Level,
+};
+
+/// An instruction of the CPU. `addx` takes two cycles, `noop` one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Noop,
+    Addx(i32),
+}
+
+type DelayBeforeInterpretation = i32;
+
+fn parse_instruction(input: &str) -> PResult<'_, Instruction> {
+    let instruction = alt((
+        value(Instruction::Noop, tag("noop")),
+        map(keyword_value("addx", signed), Instruction::Addx),
+    ));
+    return context("an instruction", instruction)(input);
+}
+
+/// One instruction per line.
+pub fn parse(input: &str) -> Result<Vec<Instruction>, AocError> {
+    return parse_all(lines(parse_instruction), input);
+}
+
+fn prefix_with_delay_before_interpretation(
+    instructions: &[Instruction],
+) -> VecDeque<(DelayBeforeInterpretation, Instruction)> {
+    VecDeque::from(
+        instructions
+            .iter()
+            .copied()
+            .map(|instruction| match instruction {
+                Instruction::Noop => (0, instruction),
+                Instruction::Addx(_) => (1, instruction),
+            })
+            .collect::<Vec<(DelayBeforeInterpretation, Instruction)>>(),
+    )
+}
+
+/// Value of the `X` register during each cycle, starting at cycle 1, until
+/// the program ends. The register is wider than the `addx` operands so
+/// neither it nor a signal strength can overflow.
+pub fn interpret_instructions(instructions: &[Instruction]) -> HashMap<i32, i64> {
+    let mut instuction_deque: VecDeque<(DelayBeforeInterpretation, Instruction)> =
+        prefix_with_delay_before_interpretation(instructions);
+
+    let mut register_history = HashMap::new();
+    let mut register: i64 = 1;
+    for cycle in 1.. {
+        register_history.insert(cycle, register);
+        trace!(Level::Debug, "cycle {}: X = {}", cycle, register);
+        match instuction_deque.pop_front() {
+            Some((0, instruction)) => match instruction {
+                Instruction::Noop => (),
+                Instruction::Addx(value) => {
+                    register += i64::from(value);
+                }
+            },
+            Some((count, instruction)) => {
+                instuction_deque.push_front((count - 1, instruction));
+            }
+            None => break,
+        };
+    }
+    register_history
+}
+
+/// Sum of the signal strengths during cycles 20, 60, ..., 220.
+pub fn part1(instructions: &[Instruction]) -> Result<i64, AocError> {
+    let registry_history = interpret_instructions(instructions);
+
+    let intersting_cycles = vec![20, 60, 100, 140, 180, 220];
+    intersting_cycles
+        .into_iter()
+        .map(|cycle| match registry_history.get(&cycle) {
+            Some(register) => Ok(i64::from(cycle) * register),
+            None => Err(AocError::validation(format!(
+                "the program stops before cycle {}",
+                cycle
+            ))),
+        })
+        .sum()
+}
+
+/// At least `size` instructions, and enough of them to run for 240 cycles.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut lines = Vec::new();
+    let mut register = 1;
+    // the value of the register during each cycle, from cycle 1
+    let mut during = Vec::new();
+    while lines.len() < size || during.len() < 240 {
+        if rng.one_in(3) {
+            lines.push("noop".to_string());
+            during.push(register);
+        } else {
+            let value = rng.range(0, 40) as i64 - 20;
+            lines.push(format!("addx {}", value));
+            during.extend([register, register]);
+            register += value;
+        }
+    }
+    let part1: i64 = (20..=220)
+        .step_by(40)
+        .map(|cycle| cycle as i64 * during[cycle - 1])
+        .sum();
+    return Generated {
+        input: lines.join("\n"),
+        part1: Some(part1.into()),
+        part2: None,
+    };
+}
+
+/// Part 1 listing the value of the register during every cycle.
+fn reference_part1(instructions: &[Instruction]) -> Option<i64> {
+    let mut register: i64 = 1;
+    let mut during = Vec::new();
+    for instruction in instructions {
+        match instruction {
+            Instruction::Noop => during.push(register),
+            Instruction::Addx(value) => {
+                during.extend([register, register]);
+                register += i64::from(*value);
+            }
+        }
+    }
+    during.push(register);
+    return [20, 60, 100, 140, 180, 220]
+        .iter()
+        .map(|&cycle| {
+            during
+                .get(cycle as usize - 1)
+                .map(|register| cycle as i64 * register)
+        })
+        .sum();
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Instruction>;
+
+    const YEAR: u16 = 2022;
+
+    const DAY: u8 = 10;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        return parse(input);
+    }
+
+    fn part1(&self, instructions: &Self::Input) -> Result<Answer, AocError> {
+        return part1(instructions).map(Answer::from);
+    }
+
+    fn reference(&self, part: Part, instructions: &Self::Input) -> Result<Answer, AocError> {
+        return match part {
+            Part::One => reference_part1(instructions)
+                .map(Answer::from)
+                .ok_or_else(|| AocError::validation("the program stops too early")),
+            Part::Two => Err(AocError::NotImplemented { day: 10, part }),
+        };
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        return Some(generate(rng, size));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::answers::known_answer;
+    use crate::input::{read_input, read_variant};
+    use crate::solution::{Part, Puzzle};
+
+    #[test]
+    fn should_intepret_instructions() {
+        let instructions = Vec::from([
+            Instruction::Noop,
+            Instruction::Addx(3),
+            Instruction::Addx(-5),
+        ]);
+        let res = interpret_instructions(&instructions);
+        assert_eq!(res.get(&1), Some(&1));
+        assert_eq!(res.get(&2), Some(&1));
+        assert_eq!(res.get(&3), Some(&1));
+        assert_eq!(res.get(&4), Some(&4));
+        assert_eq!(res.get(&5), Some(&4));
+        assert_eq!(res.get(&6), Some(&-1));
+    }
+
+    #[test]
+    fn should_reject_invalid_programs() {
+        assert!(matches!(parse("noop\nmulx 3"), Err(AocError::Parse(_))));
+        assert!(matches!(parse("addx three"), Err(AocError::Parse(_))));
+        assert!(matches!(
+            Day10.run(Part::One, "noop\naddx 3"),
+            Err(AocError::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn should_not_overflow_with_large_operands() {
+        let instructions = vec![Instruction::Addx(i32::MAX); 120];
+        let expected: i64 = [20, 60, 100, 140, 180, 220]
+            .iter()
+            .map(|&cycle: &i64| cycle * (1 + (cycle - 1) / 2 * i64::from(i32::MAX)))
+            .sum();
+        assert_eq!(part1(&instructions), Ok(expected));
+        assert_eq!(reference_part1(&instructions), Some(expected));
+    }
+
+    #[test]
+    fn part1_example() {
+        let input = &read_variant(2022, 10, "example").unwrap();
+        assert_eq!(Day10.run(Part::One, input), Ok(13140.into()));
+    }
+
+    #[test]
+    fn part1_res() {
+        let input = &read_input(2022, 10).unwrap();
+        assert_eq!(
+            Day10.run(Part::One, input),
+            Ok(known_answer(2022, 10, Part::One))
+        );
+    }
+}
@@ -0,0 +1,198 @@
+use nom::{
+    character::complete::{anychar, space1},
+    combinator::map_opt,
+    error::context,
+    sequence::separated_pair,
+};
+
+use crate::{
+    error::AocError,
+    generate::{Generated, Rng},
+    parsing::{lines, parse_all, PResult},
+    solution::{Answer, Part, Solution},
+    trace,
+    trace::Level,
+};
+
+/// A shape, written `A`/`X` for rock, `B`/`Y` for paper and `C`/`Z` for scissors.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum Options {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+/// What the opponent played, then what I played.
+pub type Round = (Options, Options);
+
+fn parse_option(input: &str) -> PResult<'_, Options> {
+    let letter = map_opt(anychar, |letter| match letter {
+        'A' | 'X' => Some(Options::Rock),
+        'B' | 'Y' => Some(Options::Paper),
+        'C' | 'Z' => Some(Options::Scissors),
+        _ => None,
+    });
+    return context("one of A, B, C, X, Y or Z", letter)(input);
+}
+
+/// One round per line, such as `A Y`.
+pub fn parse(input: &str) -> Result<Vec<Round>, AocError> {
+    return parse_all(
+        lines(separated_pair(parse_option, space1, parse_option)),
+        input,
+    );
+}
+
+/// Total score when the second column is what I play.
+pub fn part1(rounds: &[Round]) -> u32 {
+    return rounds
+        .iter()
+        .map(|&(opponentPlayed, iPlayed)| {
+            let looseDrawWinScore = if opponentPlayed == iPlayed {
+                3
+            } else if (opponentPlayed == Options::Scissors && iPlayed == Options::Paper)
+                || (opponentPlayed == Options::Paper && iPlayed == Options::Rock)
+                || (opponentPlayed == Options::Rock && iPlayed == Options::Scissors)
+            {
+                0
+            } else {
+                6
+            };
+
+            let whatIPlayedScore = match iPlayed {
+                Options::Rock => 1,
+                Options::Paper => 2,
+                Options::Scissors => 3,
+            };
+
+            trace!(
+                Level::Debug,
+                "{:?} against {:?} scores {} + {}",
+                iPlayed,
+                opponentPlayed,
+                whatIPlayedScore,
+                looseDrawWinScore
+            );
+            return looseDrawWinScore + whatIPlayedScore;
+        })
+        .sum();
+}
+
+/// `size` random rounds. The second column read as the outcome of the round
+/// gives the answer of part 2.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let rounds: Vec<(u64, u64)> = (0..size.max(1))
+        .map(|_| (rng.range(0, 2), rng.range(0, 2)))
+        .collect();
+    let input = rounds
+        .iter()
+        .map(|&(opponent, column)| {
+            format!(
+                "{} {}",
+                ["A", "B", "C"][opponent as usize],
+                ["X", "Y", "Z"][column as usize]
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+    // 0 for a draw, 1 for a win and 2 for a loss
+    let outcome = |opponent: u64, me: u64| (me + 3 - opponent) % 3;
+    let part1: u64 = rounds
+        .iter()
+        .map(|&(opponent, me)| me + 1 + [3, 6, 0][outcome(opponent, me) as usize])
+        .sum();
+    let part2: u64 = rounds
+        .iter()
+        .map(|&(opponent, column)| (opponent + column + 2) % 3 + 1 + 3 * column)
+        .sum();
+    return Generated {
+        input,
+        part1: Some(part1.into()),
+        part2: Some(part2.into()),
+    };
+}
+
+/// Part 1 from the table of the nine possible rounds.
+fn reference_part1(rounds: &[Round]) -> u32 {
+    use Options::*;
+    return rounds
+        .iter()
+        .map(|round| match round {
+            (Rock, Rock) => 1 + 3,
+            (Paper, Rock) => 1,
+            (Scissors, Rock) => 1 + 6,
+            (Rock, Paper) => 2 + 6,
+            (Paper, Paper) => 2 + 3,
+            (Scissors, Paper) => 2,
+            (Rock, Scissors) => 3,
+            (Paper, Scissors) => 3 + 6,
+            (Scissors, Scissors) => 3 + 3,
+        })
+        .sum();
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Round>;
+
+    const YEAR: u16 = 2022;
+
+    const DAY: u8 = 2;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        return parse(input);
+    }
+
+    fn part1(&self, rounds: &Self::Input) -> Result<Answer, AocError> {
+        return Ok(part1(rounds).into());
+    }
+
+    fn reference(&self, part: Part, rounds: &Self::Input) -> Result<Answer, AocError> {
+        return match part {
+            Part::One => Ok(reference_part1(rounds).into()),
+            Part::Two => Err(AocError::NotImplemented { day: 2, part }),
+        };
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        return Some(generate(rng, size));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answers::known_answer;
+    use crate::input::read_input;
+    use crate::solution::{Part, Puzzle};
+
+    #[test]
+    fn part1_example() {
+        let input = "A Y
+        B X
+        C Z";
+        assert_eq!(Day2.run(Part::One, input), Ok(15.into()));
+    }
+
+    #[test]
+    fn should_reject_unknown_options() {
+        assert!(matches!(
+            Day2.run(Part::One, "A Y\nB W"),
+            Err(AocError::Parse(_))
+        ));
+        assert!(matches!(
+            Day2.run(Part::One, "A Y X"),
+            Err(AocError::Parse(_))
+        ));
+    }
+
+    #[test]
+    fn part1_result() {
+        let input = read_input(2022, 2).unwrap();
+        assert_eq!(
+            Day2.run(Part::One, &input),
+            Ok(known_answer(2022, 2, Part::One))
+        );
+    }
+}
@@ -0,0 +1,282 @@
+use std::collections::HashSet;
+
+use nom::{
+    character::complete::{alpha1, space0},
+    error::context,
+    sequence::preceded,
+};
+
+use crate::{
+    error::AocError,
+    generate::{Generated, Rng},
+    parsing::{line_end, lines, parse_all},
+    solution::{Answer, Part, Solution},
+    trace,
+    trace::Level,
+};
+
+/// A line split into its two compartments of equal size.
+#[derive(Debug)]
+pub struct Rucksack {
+    compatiment1: HashSet<char>,
+    compatiment2: HashSet<char>,
+}
+
+impl TryFrom<&str> for Rucksack {
+    type Error = AocError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if let Some(item) = value.chars().find(|c| !c.is_ascii_alphabetic()) {
+            return Err(AocError::parse(format!(
+                "unknown item {:?} in {}",
+                item, value
+            )));
+        }
+        let size = value.len();
+        if !size.is_multiple_of(2) {
+            return Err(AocError::validation(format!(
+                "rucksack {} cannot be split into two compartments",
+                value
+            )));
+        }
+        let (left, right) = value.split_at(size / 2);
+        let rucksack = Rucksack {
+            compatiment1: HashSet::from_iter(left.chars()),
+            compatiment2: HashSet::from_iter(right.chars()),
+        };
+        return Ok(rucksack);
+    }
+}
+
+impl Rucksack {
+    /// Items found in both compartments.
+    pub fn common_items(&self) -> HashSet<char> {
+        return self
+            .compatiment1
+            .intersection(&self.compatiment2)
+            .copied()
+            .collect();
+    }
+}
+
+struct Priority {
+    priority: u32,
+}
+
+impl TryFrom<char> for Priority {
+    type Error = AocError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        let n = value as u32;
+        if n >= 'a' as u32 && n <= 'z' as u32 {
+            let p = n - 'a' as u32 + 1;
+            return Ok(Priority { priority: p });
+        } else if n >= 'A' as u32 && n <= 'Z' as u32 {
+            let p = n - 'A' as u32 + 27;
+            return Ok(Priority { priority: p });
+        } else {
+            return Err(AocError::parse(format!(
+                "unable to find priority from {:?}",
+                value
+            )));
+        };
+    }
+}
+
+fn sumPriorities(rucksacks: &[Rucksack]) -> Result<u32, AocError> {
+    let priorities: Result<Vec<Priority>, AocError> = rucksacks
+        .iter()
+        .map(|rucksack| {
+            let intersection: Option<&char> = rucksack
+                .compatiment1
+                .intersection(&rucksack.compatiment2)
+                .last();
+            return match intersection {
+                None => Err(AocError::validation("no item is in both compartments")),
+                Some(&char) => {
+                    trace!(Level::Debug, "{:?} is in both compartments", char);
+                    Priority::try_from(char)
+                }
+            };
+        })
+        .collect();
+
+    return priorities.map(|priorities| priorities.into_iter().map(|p| p.priority).sum());
+}
+
+/// One rucksack per line, made of ASCII letters.
+pub fn parse(input: &str) -> Result<Vec<Rucksack>, AocError> {
+    let items = |input| {
+        let (rest, items) = preceded(space0, alpha1)(input)?;
+        let (rest, _) = context("a letter", line_end)(rest)?;
+        return Ok((rest, items));
+    };
+    return parse_all(lines(items), input)?
+        .into_iter()
+        .map(Rucksack::try_from)
+        .collect();
+}
+
+/// Sum of the priorities of the item found in both compartments of each rucksack.
+pub fn part1(rucksacks: &[Rucksack]) -> Result<u32, AocError> {
+    return sumPriorities(rucksacks);
+}
+
+/// The items, in order of priority.
+const ITEMS: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// A rucksack of `allowed` items whose compartments only share `common`, and
+/// holding `badge`.
+fn generate_rucksack(rng: &mut Rng, allowed: &[char], common: char, badge: char) -> String {
+    let mut others: Vec<char> = allowed.iter().copied().filter(|&c| c != common).collect();
+    rng.shuffle(&mut others);
+    let (left_items, right_items) = others.split_at(rng.range(1, others.len() as u64 - 1) as usize);
+    let mut left = vec![common];
+    let mut right = vec![common];
+    if badge != common {
+        if left_items.contains(&badge) {
+            left.push(badge);
+        } else {
+            right.push(badge);
+        }
+    }
+    let size = rng.range(2, 16) as usize;
+    while left.len() < size {
+        left.push(*rng.pick(left_items));
+    }
+    while right.len() < size {
+        right.push(*rng.pick(right_items));
+    }
+    rng.shuffle(&mut left);
+    rng.shuffle(&mut right);
+    return left.into_iter().chain(right).collect();
+}
+
+/// `size` rucksacks, rounded up to groups of three elves sharing one badge.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let items: Vec<char> = ITEMS.chars().collect();
+    let priority = |item: char| ITEMS.find(item).unwrap_or_default() as u64 + 1;
+    let mut rucksacks = Vec::new();
+    let (mut part1, mut part2) = (0, 0);
+    for _ in 0..size.max(1).div_ceil(3) {
+        let badge = *rng.pick(&items);
+        let mut others: Vec<char> = items.iter().copied().filter(|&c| c != badge).collect();
+        rng.shuffle(&mut others);
+        // each item but the badge is missing from one of the rucksacks
+        let missing: Vec<&[char]> = others.chunks(others.len().div_ceil(3)).collect();
+        for missing in missing {
+            let allowed: Vec<char> = items
+                .iter()
+                .copied()
+                .filter(|item| !missing.contains(item))
+                .collect();
+            let common = *rng.pick(&allowed);
+            rucksacks.push(generate_rucksack(rng, &allowed, common, badge));
+            part1 += priority(common);
+        }
+        part2 += priority(badge);
+    }
+    return Generated {
+        input: rucksacks.join("\n"),
+        part1: Some(part1.into()),
+        part2: Some(part2.into()),
+    };
+}
+
+/// Part 1 trying every item against both compartments.
+fn reference_part1(rucksacks: &[Rucksack]) -> Result<u32, AocError> {
+    let mut sum = 0;
+    for rucksack in rucksacks {
+        let common: Vec<usize> = ITEMS
+            .chars()
+            .enumerate()
+            .filter(|(_, item)| {
+                rucksack.compatiment1.contains(item) && rucksack.compatiment2.contains(item)
+            })
+            .map(|(index, _)| index + 1)
+            .collect();
+        match common[..] {
+            [priority] => sum += priority as u32,
+            _ => {
+                return Err(AocError::validation(format!(
+                    "{} items are in both compartments",
+                    common.len()
+                )))
+            }
+        }
+    }
+    return Ok(sum);
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Vec<Rucksack>;
+
+    const YEAR: u16 = 2022;
+
+    const DAY: u8 = 3;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        return parse(input);
+    }
+
+    fn part1(&self, rucksacks: &Self::Input) -> Result<Answer, AocError> {
+        return part1(rucksacks).map(Answer::from);
+    }
+
+    fn reference(&self, part: Part, rucksacks: &Self::Input) -> Result<Answer, AocError> {
+        return match part {
+            Part::One => reference_part1(rucksacks).map(Answer::from),
+            Part::Two => Err(AocError::NotImplemented { day: 3, part }),
+        };
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        return Some(generate(rng, size));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answers::known_answer;
+    use crate::input::read_input;
+    use crate::solution::{Part, Puzzle};
+
+    #[test]
+    fn part1_example() {
+        let input = "vJrwpWtwJgWrhcsFMMfFFhFp
+        jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+        PmmdzqPrVvPwwTWBwg
+        wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+        ttgJtRGJQctTZtZT
+        CrZsJsPPZsGzwwsLwLmpwMDw";
+        assert_eq!(Day3.run(Part::One, input), Ok(157.into()));
+    }
+
+    #[test]
+    fn should_reject_malformed_rucksacks() {
+        assert!(matches!(
+            Day3.run(Part::One, "vJrwpW$wJgWr"),
+            Err(AocError::Parse(_))
+        ));
+        assert!(matches!(
+            Day3.run(Part::One, "abc"),
+            Err(AocError::Validation(_))
+        ));
+        assert!(matches!(
+            Day3.run(Part::One, "abcd"),
+            Err(AocError::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn part1_resutl() {
+        let input = read_input(2022, 3).unwrap();
+        assert_eq!(
+            Day3.run(Part::One, &input),
+            Ok(known_answer(2022, 3, Part::One))
+        );
+    }
+}
@@ -0,0 +1,499 @@
+use nom::{
+    bytes::complete::{tag, take_until},
+    error::context,
+    sequence::{separated_pair, tuple},
+};
+
+use crate::{
+    error::AocError,
+    generate::{Generated, Rng},
+    parsing::{keyword_value, lines, parse_all, unsigned, PResult},
+    solution::{Answer, Part, Solution},
+    trace,
+    trace::Level,
+};
+
+/// Stacks of crates, numbered from 1, each listed from the bottom to the top.
+#[derive(PartialEq, Debug, Clone)]
+pub struct Stacks {
+    stacks: Vec<Vec<char>>,
+}
+
+impl Stacks {
+    pub fn new(stacks: Vec<Vec<char>>) -> Self {
+        return Stacks { stacks };
+    }
+
+    pub fn stacks(&self) -> &[Vec<char>] {
+        return &self.stacks;
+    }
+
+    /// The crate on top of each stack, empty stacks being skipped.
+    pub fn top_crates(&self) -> String {
+        return self.stacks.iter().flat_map(|stack| stack.last()).collect();
+    }
+}
+
+/// `move 3 from 1 to 2`, stacks being numbered from 1.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct Instruction {
+    pub number_of_elements_to_move: usize,
+    pub from: usize,
+    pub to: usize,
+}
+
+#[derive(PartialEq, Debug)]
+pub struct Instructions {
+    pub instructions: Vec<Instruction>,
+}
+
+//     [D]
+// [N] [C]
+// [Z] [M] [P]
+//  1   2   3
+
+// move 1 from 2 to 1
+// move 3 from 1 to 3
+// move 2 from 2 to 1
+// move 1 from 1 to 2
+
+fn parse_stack_line(line: &str) -> Vec<Option<char>> {
+    let lineLen = line.len();
+    let mut res = Vec::new();
+    for idx in (1..(lineLen - 1)).step_by(4) {
+        res.push(line.chars().nth(idx).filter(|e| e != &' '));
+    }
+    return res;
+}
+
+fn parseInstruction(input: &str) -> PResult<'_, Instruction> {
+    let (rest, (number_of_elements_to_move, from, to)) = tuple((
+        keyword_value("move", unsigned),
+        keyword_value("from", unsigned),
+        keyword_value("to", unsigned),
+    ))(input)?;
+    return Ok((
+        rest,
+        Instruction {
+            number_of_elements_to_move,
+            from,
+            to,
+        },
+    ));
+}
+
+fn validate_instruction(
+    instruction: &Instruction,
+    number_of_stacks: usize,
+) -> Result<(), AocError> {
+    let is_valid_stack = |stack: usize| stack >= 1 && stack <= number_of_stacks;
+    if is_valid_stack(instruction.from) && is_valid_stack(instruction.to) {
+        return Ok(());
+    }
+    return Err(AocError::validation(format!(
+        "instruction {:?} refers to a stack outside of 1..={}",
+        instruction, number_of_stacks
+    )));
+}
+
+/// The drawing of the stacks followed by the rearrangement procedure. Every
+/// instruction is checked to refer to an existing stack.
+pub fn parse(input: &str) -> Result<(Stacks, Instructions), AocError> {
+    let drawing = context("a drawing followed by a blank line", take_until("\n\n"));
+    let (drawing, instructions) = parse_all(
+        separated_pair(drawing, tag("\n\n"), lines(parseInstruction)),
+        input,
+    )?;
+    let mut stackLines: Vec<Vec<Option<char>>> = drawing
+        .lines()
+        .filter(|line| line.contains('['))
+        .map(parse_stack_line)
+        .collect();
+    let mut stacks: Vec<Vec<char>> = Vec::new();
+    let mut isInitiazed = false;
+    // let mut map: HashMap<(usize, usize), &char> = HashMap::new();
+    stackLines.reverse();
+    for (lineIndex, line) in stackLines.iter().enumerate() {
+        trace!(
+            Level::Debug,
+            "crate line {} from the bottom: {:?}",
+            lineIndex,
+            line
+        );
+
+        if !isInitiazed {
+            stacks = Vec::new();
+            for _ in line {
+                stacks.push(Vec::new());
+            }
+            trace!(Level::Debug, "{} stacks", stacks.len());
+            isInitiazed = true;
+        }
+
+        if line.len() > stacks.len() {
+            return Err(AocError::validation(format!(
+                "crate line {} is wider than the bottom of the stacks",
+                lineIndex
+            )));
+        }
+
+        line.iter().enumerate().for_each(|(columIndex, element)| {
+            element.iter().for_each(|e| {
+                stacks[columIndex].push(*e);
+            });
+        });
+    }
+
+    if stacks.is_empty() {
+        return Err(AocError::validation("the drawing has no stacks"));
+    }
+    for instruction in &instructions {
+        validate_instruction(instruction, stacks.len())?;
+    }
+    trace!(
+        Level::Info,
+        "{} stacks, {} instructions",
+        stacks.len(),
+        instructions.len()
+    );
+    return Ok((Stacks { stacks }, Instructions { instructions }));
+}
+
+/// Moves the crates one by one, reversing their order.
+pub fn applyOneAtATime(instruction: Instruction, mut stacks: Stacks) -> Stacks {
+    for _ in 0..(instruction.number_of_elements_to_move) {
+        let element = stacks.stacks[instruction.from - 1].pop();
+        if let Some(c) = element {
+            stacks.stacks[instruction.to - 1].push(c);
+        }
+    }
+    return stacks;
+}
+
+/// Moves the crates all at once, keeping their order.
+pub fn applySeveral(instruction: Instruction, mut stacks: Stacks) -> Stacks {
+    let mut temp: Vec<char> = Vec::new();
+    for _ in 0..(instruction.number_of_elements_to_move) {
+        stacks.stacks[instruction.from - 1]
+            .pop()
+            .into_iter()
+            .for_each(|c| temp.push(c))
+    }
+    temp.reverse();
+    temp.into_iter()
+        .for_each(|c| stacks.stacks[instruction.to - 1].push(c));
+    return stacks;
+}
+
+/// Applies every instruction with `apply` and reads the top crates.
+pub fn partLogic(
+    stacks: &Stacks,
+    instruction: &Instructions,
+    apply: &dyn Fn(Instruction, Stacks) -> Stacks,
+) -> Result<String, AocError> {
+    let updatedStack = instruction
+        .instructions
+        .iter()
+        .fold(stacks.clone(), |acc, &i| {
+            let stacks = apply(i, acc);
+            trace!(Level::Debug, "{:?}: {:?}", i, stacks.stacks);
+            stacks
+        });
+    return Ok(updatedStack.top_crates());
+}
+
+pub fn part1(stacks: &Stacks, instructions: &Instructions) -> Result<String, AocError> {
+    return partLogic(stacks, instructions, &applyOneAtATime);
+}
+
+pub fn part2(stacks: &Stacks, instructions: &Instructions) -> Result<String, AocError> {
+    return partLogic(stacks, instructions, &applySeveral);
+}
+
+/// `size` moves on 2 to 9 stacks. No move empties a stack, so that every
+/// stack has a crate on top in the end.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let letters: Vec<char> = ('A'..='Z').collect();
+    let mut stacks: Vec<Vec<char>> = (0..rng.range(2, 9))
+        .map(|_| (0..rng.range(1, 8)).map(|_| *rng.pick(&letters)).collect())
+        .collect();
+    if stacks[0].len() < 2 {
+        stacks[0].push(*rng.pick(&letters));
+    }
+
+    let height = stacks.iter().map(Vec::len).max().unwrap_or_default();
+    let mut lines: Vec<String> = (0..height)
+        .rev()
+        .map(|level| {
+            return stacks
+                .iter()
+                .map(|stack| match stack.get(level) {
+                    Some(c) => format!("[{}]", c),
+                    None => "   ".to_string(),
+                })
+                .collect::<Vec<String>>()
+                .join(" ");
+        })
+        .collect();
+    lines.push(
+        (1..=stacks.len())
+            .map(|number| format!(" {} ", number))
+            .collect::<Vec<String>>()
+            .join(" "),
+    );
+    lines.push(String::new());
+
+    let (mut one_at_a_time, mut several) = (stacks.clone(), stacks);
+    for _ in 0..size.max(1) {
+        let candidates: Vec<usize> = (0..several.len())
+            .filter(|&stack| several[stack].len() >= 2)
+            .collect();
+        let from = *rng.pick(&candidates);
+        let to = (from + rng.range(1, several.len() as u64 - 1) as usize) % several.len();
+        let count = rng.range(1, several[from].len() as u64 - 1) as usize;
+        lines.push(format!("move {} from {} to {}", count, from + 1, to + 1));
+
+        for _ in 0..count {
+            let moved = one_at_a_time[from].pop().unwrap_or_default();
+            one_at_a_time[to].push(moved);
+        }
+        let start = several[from].len() - count;
+        let moved: Vec<char> = several[from].drain(start..).collect();
+        several[to].extend(moved);
+    }
+    let tops = |stacks: &[Vec<char>]| -> String {
+        return stacks.iter().filter_map(|stack| stack.last()).collect();
+    };
+    return Generated {
+        input: lines.join("\n"),
+        part1: Some(tops(&one_at_a_time).into()),
+        part2: Some(tops(&several).into()),
+    };
+}
+
+/// Moves the crates of each instruction as a slice, reversed when they are
+/// moved one at a time, and reads the top crates.
+fn reference_top_crates(
+    stacks: &Stacks,
+    instructions: &Instructions,
+    one_at_a_time: bool,
+) -> String {
+    let mut stacks = stacks.stacks.clone();
+    for instruction in &instructions.instructions {
+        let from = &mut stacks[instruction.from - 1];
+        let mut moved = from.split_off(
+            from.len()
+                .saturating_sub(instruction.number_of_elements_to_move),
+        );
+        if one_at_a_time {
+            moved.reverse();
+        }
+        stacks[instruction.to - 1].extend(moved);
+    }
+    return stacks.iter().filter_map(|stack| stack.last()).collect();
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = (Stacks, Instructions);
+
+    const YEAR: u16 = 2022;
+
+    const DAY: u8 = 5;
+
+    const DEDENT: bool = false;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        return parse(input);
+    }
+
+    fn part1(&self, (stacks, instructions): &Self::Input) -> Result<Answer, AocError> {
+        return part1(stacks, instructions).map(Answer::from);
+    }
+
+    fn part2(&self, (stacks, instructions): &Self::Input) -> Result<Answer, AocError> {
+        return part2(stacks, instructions).map(Answer::from);
+    }
+
+    fn reference(
+        &self,
+        part: Part,
+        (stacks, instructions): &Self::Input,
+    ) -> Result<Answer, AocError> {
+        return Ok(reference_top_crates(stacks, instructions, part == Part::One).into());
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        return Some(generate(rng, size));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answers::known_answer;
+    use crate::input::read_input;
+    use crate::solution::{Part, Puzzle};
+
+    #[test]
+    fn should_apply_an_instruction() {
+        let stacks = Stacks {
+            stacks: Vec::from([Vec::from(['N', 'Z']), Vec::from(['A'])]),
+        };
+        let instruction = Instruction {
+            number_of_elements_to_move: 1,
+            from: 1,
+            to: 2,
+        };
+        assert_eq!(
+            applyOneAtATime(instruction, stacks),
+            Stacks {
+                stacks: Vec::from([Vec::from(['N']), Vec::from(['A', 'Z'])])
+            }
+        );
+    }
+
+    #[test]
+    fn should_apply_an_instruction_two_times() {
+        let stacks = Stacks {
+            stacks: Vec::from([Vec::from(['N', 'Z']), Vec::from(['A'])]),
+        };
+        let instruction = Instruction {
+            number_of_elements_to_move: 2,
+            from: 1,
+            to: 2,
+        };
+        assert_eq!(
+            applyOneAtATime(instruction, stacks),
+            Stacks {
+                stacks: Vec::from([Vec::new(), Vec::from(['A', 'Z', 'N'])])
+            }
+        );
+    }
+
+    #[test]
+    fn should_parse() {
+        let input = "    [D]    
+[N] [C]    
+[Z] [M] [P]
+ 1   2   3 
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2";
+        assert_eq!(
+            parse(input),
+            Ok((
+                Stacks {
+                    stacks: Vec::from([
+                        Vec::from(['Z', 'N']),
+                        Vec::from(['M', 'C', 'D']),
+                        Vec::from(['P']),
+                    ])
+                },
+                Instructions {
+                    instructions: Vec::from([
+                        Instruction {
+                            number_of_elements_to_move: 1,
+                            from: 2,
+                            to: 1
+                        },
+                        Instruction {
+                            number_of_elements_to_move: 3,
+                            from: 1,
+                            to: 3
+                        },
+                        Instruction {
+                            number_of_elements_to_move: 2,
+                            from: 2,
+                            to: 1
+                        },
+                        Instruction {
+                            number_of_elements_to_move: 1,
+                            from: 1,
+                            to: 2
+                        },
+                    ])
+                }
+            ))
+        )
+    }
+
+    #[test]
+    fn should_reject_invalid_instructions() {
+        let input = "[N] [C]
+ 1   2
+
+move 1 from 2";
+        assert!(matches!(parse(input), Err(AocError::Parse(_))));
+
+        let input = "[N] [C]
+ 1   2
+
+move 1 from 3 to 1";
+        assert!(matches!(parse(input), Err(AocError::Validation(_))));
+    }
+
+    #[test]
+    fn part1_example() {
+        let input = "    [D]    
+[N] [C]    
+[Z] [M] [P]
+ 1   2   3 
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2";
+        assert_eq!(Day5.run(Part::One, input), Ok("CMZ".into()));
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = "    [D]    
+[N] [C]    
+[Z] [M] [P]
+ 1   2   3 
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2";
+        assert_eq!(Day5.run(Part::Two, input), Ok("MCD".into()));
+    }
+
+    #[test]
+    fn should_keep_the_drawing_indentation() {
+        let input = "    [D]    \r
+[N] [C]    \r
+[Z] [M] [P]\r
+ 1   2   3 \r
+\r
+move 1 from 2 to 1\r
+move 3 from 1 to 3\r
+move 2 from 2 to 1\r
+move 1 from 1 to 2\r
+";
+        assert_eq!(Day5.run(Part::One, input), Ok("CMZ".into()));
+    }
+
+    #[test]
+    fn part1_resutl() {
+        let input = read_input(2022, 5).unwrap();
+        assert_eq!(
+            Day5.run(Part::One, &input),
+            Ok(known_answer(2022, 5, Part::One))
+        );
+    }
+
+    #[test]
+    fn part2_resutl() {
+        let input = read_input(2022, 5).unwrap();
+        assert_eq!(
+            Day5.run(Part::Two, &input),
+            Ok(known_answer(2022, 5, Part::Two))
+        );
+    }
+}
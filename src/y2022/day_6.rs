@@ -0,0 +1,206 @@
+use std::{collections::HashSet, ops::ControlFlow};
+
+use crate::{
+    error::AocError,
+    generate::{Generated, Rng},
+    solution::{Answer, Part, Solution},
+    trace,
+    trace::Level,
+};
+
+fn hasDuplicateElements(window: &[(usize, char)]) -> bool {
+    let uniqueSetOfElements: HashSet<char> = window.iter().map(|a| a.1).collect();
+    return window.len() != uniqueSetOfElements.len();
+}
+
+/// Number of characters read when the last `numberOfChar` ones are all different.
+pub fn find_marker(input: &str, numberOfChar: usize) -> Result<usize, AocError> {
+    if numberOfChar == 0 {
+        return Err(AocError::validation(
+            "a marker needs at least one character",
+        ));
+    }
+    let charIndices: Vec<(usize, char)> = input.char_indices().collect();
+
+    let maybeWindowIndex: ControlFlow<usize, usize> =
+        charIndices
+            .windows(numberOfChar)
+            .try_fold(numberOfChar, |n, window| {
+                if hasDuplicateElements(window) {
+                    return ControlFlow::Continue(n + 1);
+                } else {
+                    return ControlFlow::Break(n);
+                };
+            });
+
+    return match maybeWindowIndex {
+        ControlFlow::Break(idx) => {
+            trace!(
+                Level::Info,
+                "{:?} ends at {}",
+                input
+                    .chars()
+                    .skip(idx - numberOfChar)
+                    .take(numberOfChar)
+                    .collect::<String>(),
+                idx
+            );
+            Ok(idx)
+        }
+        _ => Err(AocError::validation(format!(
+            "no window of {} distinct characters",
+            numberOfChar
+        ))),
+    };
+}
+
+/// End of the start-of-packet marker.
+pub fn part1(input: &str) -> Result<usize, AocError> {
+    return find_marker(input, 4);
+}
+
+/// End of the start-of-message marker.
+pub fn part2(input: &str) -> Result<usize, AocError> {
+    return find_marker(input, 14);
+}
+
+/// A stream of about `size` characters. It starts with three letters only,
+/// so that the markers come later, and ends with a message marker.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let letters: Vec<char> = ('a'..='z').collect();
+    let mut stream: Vec<char> = (0..rng.range(0, size as u64 / 2))
+        .map(|_| *rng.pick(&letters[..3]))
+        .collect();
+    while stream.len() < size {
+        stream.push(*rng.pick(&letters));
+    }
+    let mut marker = letters.clone();
+    rng.shuffle(&mut marker);
+    stream.extend(&marker[..14]);
+
+    let end_of_marker = |length: usize| -> Option<Answer> {
+        return (length..=stream.len())
+            .find(|&end| {
+                let window = &stream[end - length..end];
+                return window
+                    .iter()
+                    .enumerate()
+                    .all(|(index, c)| !window[index + 1..].contains(c));
+            })
+            .map(Answer::from);
+    };
+    return Generated {
+        input: stream.iter().collect(),
+        part1: end_of_marker(4),
+        part2: end_of_marker(14),
+    };
+}
+
+/// End of the first run of `length` different characters, comparing every
+/// pair of characters of each window.
+fn reference_marker(datastream: &str, length: usize) -> Option<usize> {
+    let characters: Vec<char> = datastream.chars().collect();
+    return (length..=characters.len()).find(|&end| {
+        let window = &characters[end - length..end];
+        return (0..length).all(|i| (i + 1..length).all(|j| window[i] != window[j]));
+    });
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = String;
+
+    const YEAR: u16 = 2022;
+
+    const DAY: u8 = 6;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        return Ok(input.trim().to_string());
+    }
+
+    fn part1(&self, datastream: &Self::Input) -> Result<Answer, AocError> {
+        return part1(datastream).map(Answer::from);
+    }
+
+    fn part2(&self, datastream: &Self::Input) -> Result<Answer, AocError> {
+        return part2(datastream).map(Answer::from);
+    }
+
+    fn reference(&self, part: Part, datastream: &Self::Input) -> Result<Answer, AocError> {
+        let length = match part {
+            Part::One => 4,
+            Part::Two => 14,
+        };
+        return reference_marker(datastream, length)
+            .map(Answer::from)
+            .ok_or_else(|| AocError::validation("no marker"));
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        return Some(generate(rng, size));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answers::known_answer;
+    use crate::input::read_input;
+    use crate::solution::{Part, Puzzle};
+
+    #[test]
+    fn bvwbjplbgvbhsrlpgdmjqwftvncz_should_return_5() {
+        let input = "bvwbjplbgvbhsrlpgdmjqwftvncz";
+        assert_eq!(part1(input), Ok(5));
+    }
+
+    #[test]
+    fn nppdvjthqldpwncqszvftbrmjlhg_should_return_6() {
+        let input = "nppdvjthqldpwncqszvftbrmjlhg";
+        assert_eq!(part1(input), Ok(6));
+    }
+
+    #[test]
+    fn nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg_should_return_10() {
+        let input = "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg";
+        assert_eq!(part1(input), Ok(10));
+    }
+
+    #[test]
+    fn zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw_should_return_11() {
+        let input = "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw";
+        assert_eq!(part1(input), Ok(11));
+    }
+
+    #[test]
+    fn aaaa_should_not_find_a_marker() {
+        assert!(matches!(part1("aaaaaaaa"), Err(AocError::Validation(_))));
+    }
+
+    #[test]
+    fn empty_marker_should_be_rejected() {
+        assert!(matches!(
+            find_marker("abcd", 0),
+            Err(AocError::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn part_1() {
+        let input = read_input(2022, 6).unwrap();
+        assert_eq!(
+            Day6.run(Part::One, &input),
+            Ok(known_answer(2022, 6, Part::One))
+        );
+    }
+
+    #[test]
+    fn part_2() {
+        let input = read_input(2022, 6).unwrap();
+        assert_eq!(
+            Day6.run(Part::Two, &input),
+            Ok(known_answer(2022, 6, Part::Two))
+        );
+    }
+}
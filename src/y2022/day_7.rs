@@ -0,0 +1,555 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    hash::Hash,
+};
+
+use itertools::Itertools;
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{not_line_ending, space1},
+    combinator::{map, value},
+    error::context,
+    sequence::{separated_pair, terminated},
+};
+
+use crate::{
+    error::AocError,
+    generate::{Generated, Rng},
+    parsing::{keyword_value, line_end, lines, parse_all, unsigned, PResult},
+    solution::{Answer, Part, Solution},
+    trace,
+    trace::Level,
+};
+
+/// A line of the terminal transcript. `$ ls` lines only introduce their output.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    Cd { to: String },
+    Dir { name: String },
+    File { name: String, size: u64 },
+}
+
+/// A directory, named by its path from the root, such as `//a/e`.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct ParentDir {
+    pub name: String,
+}
+
+impl TryFrom<FileType> for ParentDir {
+    type Error = AocError;
+
+    fn try_from(value: FileType) -> Result<Self, Self::Error> {
+        match value {
+            FileType::Dir { name } => Ok(ParentDir { name }),
+            _ => Err(AocError::validation("is not a dir")),
+        }
+    }
+}
+
+/// An entry of a directory, named by its path from the root.
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub enum FileType {
+    Dir { name: String },
+    File { name: String, size: u64 },
+}
+pub trait FileTypeOps {
+    fn get_file_size(&self) -> Option<&u64>;
+}
+
+impl FileTypeOps for FileType {
+    fn get_file_size(&self) -> Option<&u64> {
+        match self {
+            FileType::File { name: _, size } => Some(size),
+            _ => None,
+        }
+    }
+}
+
+fn name(input: &str) -> PResult<'_, String> {
+    return map(not_line_ending, str::to_string)(input);
+}
+
+/// `None` for `$ ls`, which only introduces the entries listed after it.
+fn parse_line(input: &str) -> PResult<'_, Option<Command>> {
+    let line = alt((
+        map(keyword_value("$ cd", name), |to| Some(Command::Cd { to })),
+        value(None, terminated(tag("$ ls"), line_end)),
+        map(keyword_value("dir", name), |name| {
+            Some(Command::Dir { name })
+        }),
+        map(separated_pair(unsigned, space1, name), |(size, name)| {
+            Some(Command::File { name, size })
+        }),
+    ));
+    return context("a command or a directory entry", line)(input);
+}
+
+/// Reads the whole transcript, failing on the first line it does not recognize.
+pub fn parse(input: &str) -> Result<Vec<Command>, AocError> {
+    let commands = parse_all(lines(parse_line), input)?;
+    return Ok(commands.into_iter().flatten().collect());
+}
+
+/// The directory containing each entry listed in the transcript.
+pub fn create_relationship_from_commands(commands: &[Command]) -> HashMap<FileType, ParentDir> {
+    let mut current_directory_deque: VecDeque<String> = VecDeque::new();
+    let mut child_parent_relationship: HashMap<FileType, ParentDir> = HashMap::new();
+    for command in commands {
+        let parent_dir = ParentDir {
+            name: current_directory_deque.iter().rev().join("/"),
+        };
+        match command {
+            Command::Cd { to } => {
+                if to == ".." {
+                    current_directory_deque.pop_front();
+                } else {
+                    current_directory_deque.push_front(to.to_string());
+                }
+            }
+            Command::Dir { name } => {
+                child_parent_relationship.insert(
+                    FileType::Dir {
+                        name: parent_dir.name.to_string() + "/" + name,
+                    },
+                    parent_dir,
+                );
+            }
+            Command::File { name, size } => {
+                child_parent_relationship.insert(
+                    FileType::File {
+                        name: parent_dir.name.to_string() + "/" + name,
+                        size: *size,
+                    },
+                    parent_dir,
+                );
+            }
+        }
+    }
+    return child_parent_relationship;
+}
+
+/// The entries of each directory.
+pub fn create_graph_from_relationships(
+    file_parent_map: HashMap<FileType, ParentDir>,
+) -> HashMap<ParentDir, HashSet<FileType>> {
+    let map_of_vec: HashMap<ParentDir, Vec<FileType>> = file_parent_map
+        .clone()
+        .into_iter()
+        .map(|(k, v)| (v, k))
+        .into_group_map();
+
+    return map_of_vec
+        .into_iter()
+        .map(|(k, v)| (k, v.into_iter().collect::<HashSet<FileType>>()))
+        .collect();
+}
+
+/// Total size of every directory reachable from the root, nested directories
+/// included.
+pub fn compute_directory_sizes(
+    graph: HashMap<ParentDir, HashSet<FileType>>,
+) -> HashMap<ParentDir, u64> {
+    let mut edges_to_visit = VecDeque::from([ParentDir {
+        name: "/".to_string(),
+    }]);
+
+    let mut directory_sizes: HashMap<ParentDir, u64> = HashMap::new();
+    let empty = HashSet::new();
+
+    while let Some(front) = edges_to_visit.front() {
+        // directories that were never listed are considered empty
+        let children = graph.get(front).unwrap_or(&empty);
+        let children_directories: HashSet<_> = children
+            .iter()
+            .flat_map(|d| match d {
+                FileType::Dir { name } => Some(ParentDir {
+                    name: name.to_string(),
+                }),
+                _ => None,
+            })
+            .filter(|child| !directory_sizes.contains_key(child))
+            .collect();
+
+        if children_directories.is_empty() {
+            let total_size: u64 = children
+                .iter()
+                .flat_map(|child| match child {
+                    FileType::Dir { name } => directory_sizes.get(&ParentDir {
+                        name: name.to_string(),
+                    }),
+                    _file @ FileType::File { name: _, size: _ } => child.get_file_size(),
+                })
+                .sum();
+            trace!(Level::Debug, "{} weighs {}", front.name, total_size);
+            directory_sizes.insert(front.clone(), total_size);
+            edges_to_visit.pop_front();
+        } else {
+            children_directories
+                .into_iter()
+                .for_each(|directory| edges_to_visit.push_front(directory));
+        }
+    }
+    trace!(Level::Info, "{} directories", directory_sizes.len());
+    return directory_sizes;
+}
+
+/// Sum of the sizes of the directories of at most 100000.
+pub fn part1(commands: &[Command]) -> Result<u64, AocError> {
+    let child_parent_relationship = create_relationship_from_commands(commands);
+
+    let graph = create_graph_from_relationships(child_parent_relationship);
+
+    let directory_sizes = compute_directory_sizes(graph);
+
+    let high_dir_size_limit = 100000;
+    let sum_small_directories: u64 = directory_sizes
+        .into_iter()
+        .filter(|(_k, v)| v <= &high_dir_size_limit)
+        .map(|(_k, v)| v)
+        .sum();
+
+    return Ok(sum_small_directories);
+}
+
+/// Size of the smallest directory to delete to free enough space for the update.
+pub fn part2(commands: &[Command]) -> Result<u64, AocError> {
+    let child_parent_relationship = create_relationship_from_commands(commands);
+
+    let graph = create_graph_from_relationships(child_parent_relationship);
+
+    let directory_sizes = compute_directory_sizes(graph);
+
+    let total_size = 70000000;
+    let space_needed = 30000000;
+
+    let space_used = *directory_sizes
+        .get(&ParentDir {
+            name: "/".to_string(),
+        })
+        .ok_or_else(|| AocError::validation("the root directory has no size"))?;
+
+    let maybe_directory_to_remove: Option<(ParentDir, u64)> = directory_sizes
+        .into_iter()
+        .sorted_by_key(|(_, v)| *v)
+        .find(|(_, directory_size)| space_used - directory_size <= total_size - space_needed);
+
+    return match maybe_directory_to_remove {
+        Some((_, dir_size)) => Ok(dir_size),
+        None => Err(AocError::validation(
+            "no directory is big enough to free the space needed",
+        )),
+    };
+}
+
+/// A directory of the generated file system.
+struct GeneratedDir {
+    name: String,
+    directories: Vec<usize>,
+    files: Vec<(String, u64)>,
+}
+
+fn random_name(rng: &mut Rng, taken: &[&str], extension: bool) -> String {
+    let letters: Vec<char> = ('a'..='z').collect();
+    loop {
+        let mut name: String = (0..rng.range(1, 6)).map(|_| *rng.pick(&letters)).collect();
+        if extension && !rng.one_in(3) {
+            name = format!("{}.{}", name, rng.pick(&["txt", "dat", "lst", "log"]));
+        }
+        if !taken.contains(&name.as_str()) {
+            return name;
+        }
+    }
+}
+
+fn entry_names(directories: &[GeneratedDir], directory: usize) -> Vec<&str> {
+    let directory = &directories[directory];
+    return directory
+        .directories
+        .iter()
+        .map(|&child| directories[child].name.as_str())
+        .chain(directory.files.iter().map(|(name, _)| name.as_str()))
+        .collect();
+}
+
+fn transcript(directories: &[GeneratedDir], directory: usize, lines: &mut Vec<String>) {
+    lines.push(format!("$ cd {}", directories[directory].name));
+    lines.push("$ ls".to_string());
+    for &child in &directories[directory].directories {
+        lines.push(format!("dir {}", directories[child].name));
+    }
+    for (name, size) in &directories[directory].files {
+        lines.push(format!("{} {}", size, name));
+    }
+    for &child in &directories[directory].directories {
+        transcript(directories, child, lines);
+        lines.push("$ cd ..".to_string());
+    }
+}
+
+/// A transcript exploring `size` directories holding up to 4 files each. The
+/// root directory is filled past 40000000, so that space has to be freed.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut directories = vec![GeneratedDir {
+        name: "/".to_string(),
+        directories: Vec::new(),
+        files: Vec::new(),
+    }];
+    for index in 1..size.max(1) {
+        let parent = rng.below(index);
+        let name = random_name(rng, &entry_names(&directories, parent), false);
+        directories[parent].directories.push(index);
+        directories.push(GeneratedDir {
+            name,
+            directories: Vec::new(),
+            files: Vec::new(),
+        });
+    }
+    for index in 0..directories.len() {
+        for _ in 0..rng.range(0, 4) {
+            let name = random_name(rng, &entry_names(&directories, index), true);
+            directories[index].files.push((name, rng.range(1, 300000)));
+        }
+    }
+
+    // children come after their parent
+    let mut sizes = vec![0; directories.len()];
+    for (index, directory) in directories.iter().enumerate().rev() {
+        sizes[index] = directory.files.iter().map(|(_, size)| size).sum::<u64>()
+            + directory
+                .directories
+                .iter()
+                .map(|&child| sizes[child])
+                .sum::<u64>();
+    }
+    if sizes[0] <= 40000000 {
+        let filler = 40000000 - sizes[0] + rng.range(1, 10000000);
+        let name = random_name(rng, &entry_names(&directories, 0), true);
+        directories[0].files.push((name, filler));
+        sizes[0] += filler;
+    }
+
+    let mut lines = Vec::new();
+    transcript(&directories, 0, &mut lines);
+    let to_free = sizes[0] - 40000000;
+    return Generated {
+        input: lines.join("\n"),
+        part1: Some(
+            sizes
+                .iter()
+                .filter(|&&size| size <= 100000)
+                .sum::<u64>()
+                .into(),
+        ),
+        part2: sizes
+            .iter()
+            .filter(|&&size| size >= to_free)
+            .min()
+            .map(|&size| size.into()),
+    };
+}
+
+/// The size of every directory, adding each file to all the directories on
+/// its path.
+fn reference_sizes(commands: &[Command]) -> HashMap<Vec<String>, u64> {
+    let mut current: Vec<String> = Vec::new();
+    let mut sizes = HashMap::new();
+    let mut files = HashSet::new();
+    for command in commands {
+        match command {
+            Command::Cd { to } if to == "/" => current = vec!["/".to_string()],
+            Command::Cd { to } if to == ".." => {
+                current.pop();
+            }
+            Command::Cd { to } => current.push(to.clone()),
+            Command::Dir { name } => {
+                let mut path = current.clone();
+                path.push(name.clone());
+                sizes.entry(path).or_insert(0);
+            }
+            Command::File { name, size } => {
+                if files.insert((current.clone(), name.clone())) {
+                    for depth in 1..=current.len() {
+                        *sizes.entry(current[..depth].to_vec()).or_insert(0) += size;
+                    }
+                }
+            }
+        }
+    }
+    return sizes;
+}
+
+fn reference_part1(commands: &[Command]) -> u64 {
+    return reference_sizes(commands)
+        .values()
+        .filter(|&&size| size <= 100000)
+        .sum();
+}
+
+fn reference_part2(commands: &[Command]) -> Option<u64> {
+    let sizes = reference_sizes(commands);
+    let used = sizes.get(&vec!["/".to_string()])?;
+    let to_free = used.saturating_sub(70000000 - 30000000);
+    return sizes
+        .values()
+        .copied()
+        .filter(|&size| size >= to_free)
+        .min();
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Vec<Command>;
+
+    const YEAR: u16 = 2022;
+
+    const DAY: u8 = 7;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        return parse(input);
+    }
+
+    fn part1(&self, commands: &Self::Input) -> Result<Answer, AocError> {
+        return part1(commands).map(Answer::from);
+    }
+
+    fn part2(&self, commands: &Self::Input) -> Result<Answer, AocError> {
+        return part2(commands).map(Answer::from);
+    }
+
+    fn reference(&self, part: Part, commands: &Self::Input) -> Result<Answer, AocError> {
+        return match part {
+            Part::One => Ok(reference_part1(commands).into()),
+            Part::Two => reference_part2(commands)
+                .map(Answer::from)
+                .ok_or_else(|| AocError::validation("no root directory")),
+        };
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        return Some(generate(rng, size));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::answers::known_answer;
+    use crate::input::read_input;
+    use crate::solution::{Part, Puzzle};
+
+    #[test]
+    fn test_example_part1() {
+        let input = "$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k";
+        assert_eq!(Day7.run(Part::One, input), Ok(95437.into()));
+    }
+
+    #[test]
+    fn test_example_part2() {
+        let input = "$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k";
+        assert_eq!(Day7.run(Part::Two, input), Ok(24933642.into()));
+    }
+
+    #[test]
+    fn should_work_with_nested_directories() {
+        let input = "$ cd /
+$ ls
+dir a
+$ cd a
+$ ls
+dir b
+$ cd b
+$ ls
+29116 f";
+        assert_eq!(Day7.run(Part::One, input), Ok(87348.into()));
+    }
+
+    #[test]
+    fn should_reject_unknown_commands() {
+        let input = "$ cd /
+$ ls
+dir a
+$ rm a";
+        assert!(matches!(
+            Day7.run(Part::One, input),
+            Err(AocError::Parse(_))
+        ));
+        assert_eq!(
+            Day7.run(Part::One, input).unwrap_err().to_string(),
+            "parse error at line 4, column 1: expected a command or a directory entry, found \"$ rm a\""
+        );
+    }
+
+    #[test]
+    fn should_consider_unlisted_directories_empty() {
+        let input = "$ cd /
+$ ls
+dir a
+120 b";
+        assert_eq!(Day7.run(Part::One, input), Ok(120.into()));
+    }
+
+    #[test]
+    fn part1_result() {
+        let input = read_input(2022, 7).unwrap();
+        assert_eq!(
+            Day7.run(Part::One, &input),
+            Ok(known_answer(2022, 7, Part::One))
+        );
+    }
+
+    #[test]
+    fn part2_result() {
+        let input = read_input(2022, 7).unwrap();
+        assert_eq!(
+            Day7.run(Part::Two, &input),
+            Ok(known_answer(2022, 7, Part::Two))
+        );
+    }
+}
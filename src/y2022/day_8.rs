@@ -0,0 +1,346 @@
+use std::collections::HashSet;
+
+use crate::{
+    error::AocError,
+    generate::{Generated, Rng},
+    geometry::{Direction4, Point2},
+    grid::Grid,
+    parsing::{digit_grid, parse_all},
+    solution::{Answer, Part, Solution},
+    trace,
+    trace::Level,
+};
+
+/// Tree heights, every line having the same length.
+#[derive(Debug)]
+pub struct Forest {
+    trees: Grid<u32>,
+}
+
+impl Forest {
+    pub fn height(&self, tree: Coords) -> Option<u32> {
+        return self.trees.get(tree).copied();
+    }
+
+    pub fn number_of_lines(&self) -> usize {
+        return self.trees.height();
+    }
+
+    pub fn number_of_columns(&self) -> usize {
+        return self.trees.width();
+    }
+}
+
+/// `x` is the column and `y` the line, starting from the top left tree.
+pub type Coords = Point2<usize>;
+
+/// One line of digits per row of trees.
+pub fn parse_into_forest(input: &str) -> Result<Forest, AocError> {
+    let trees = parse_all(digit_grid, input)?;
+    return Ok(Forest { trees });
+}
+
+/// Looks into the forest from every tree of the edges, a tree being visible
+/// when it is taller than all the trees before it.
+fn visit_forest(forest: &Forest) -> usize {
+    let trees = &forest.trees;
+    let mut lines_of_sight: Vec<(Coords, Direction4)> = Vec::new();
+    for line_idx in 0..trees.height() {
+        lines_of_sight.push((Point2::new(0, line_idx), Direction4::Right));
+        lines_of_sight.push((Point2::new(trees.width() - 1, line_idx), Direction4::Left));
+    }
+    for colunm_idx in 0..trees.width() {
+        lines_of_sight.push((Point2::new(colunm_idx, 0), Direction4::Down));
+        lines_of_sight.push((Point2::new(colunm_idx, trees.height() - 1), Direction4::Up));
+    }
+
+    let mut visible: HashSet<Coords> = lines_of_sight.iter().map(|(edge, _)| *edge).collect();
+    trace!(Level::Info, "{} trees on the edges", visible.len());
+
+    for (edge, direction) in lines_of_sight {
+        let mut tallest = trees.get(edge).copied().unwrap_or(0);
+        for (tree, &hight) in trees.ray(edge, direction) {
+            if hight > tallest {
+                trace!(
+                    Level::Debug,
+                    "{:?} of height {} is visible looking {:?} from {:?}",
+                    tree,
+                    hight,
+                    direction,
+                    edge
+                );
+                visible.insert(tree);
+                tallest = hight;
+            }
+            if tallest == 9 {
+                break;
+            }
+        }
+    }
+    trace!(Level::Info, "{} visible trees", visible.len());
+    return visible.len();
+}
+
+/// Number of trees visible from outside the forest.
+pub fn part1(forest: &Forest) -> Result<usize, AocError> {
+    trace!(
+        Level::Info,
+        "forest of {}x{} trees",
+        forest.number_of_lines(),
+        forest.number_of_columns()
+    );
+    let res = visit_forest(forest);
+    return Ok(res);
+}
+
+/// The trees that could still block the view of the next tree along a line,
+/// from the tallest to the closest.
+#[derive(Default)]
+struct Sightline {
+    blocking: Vec<(usize, u32)>,
+}
+
+impl Sightline {
+    /// Whether the tree at `index` along the line is taller than all the trees
+    /// before it, and how many of them it sees before one at least as tall
+    /// blocks the view.
+    fn look(&mut self, index: usize, height: u32) -> (bool, usize) {
+        while self.blocking.last().is_some_and(|&(_, tree)| tree < height) {
+            self.blocking.pop();
+        }
+        let sight = match self.blocking.last() {
+            Some(&(tree, _)) => (false, index - tree),
+            None => (true, index),
+        };
+        self.blocking.push((index, height));
+        return sight;
+    }
+}
+
+/// A forest of `size` by `size` random trees, its answers being computed
+/// looking along each line and column both ways. The columns are looked
+/// along all at once, line after line, to read the trees in order.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let side = size.max(1);
+    let heights: Vec<Vec<u32>> = (0..side)
+        .map(|_| (0..side).map(|_| rng.range(0, 9) as u32).collect())
+        .collect();
+
+    let mut visible = vec![vec![false; side]; side];
+    let mut scores = vec![vec![1; side]; side];
+    let mut see = |row: usize, column: usize, (from_edge, distance): (bool, usize)| {
+        visible[row][column] |= from_edge;
+        scores[row][column] *= distance;
+    };
+    for (row, line) in heights.iter().enumerate() {
+        let (mut from_left, mut from_right) = (Sightline::default(), Sightline::default());
+        for (column, &height) in line.iter().enumerate() {
+            see(row, column, from_left.look(column, height));
+        }
+        for (column, &height) in line.iter().enumerate().rev() {
+            see(row, column, from_right.look(side - 1 - column, height));
+        }
+    }
+    let mut from_top: Vec<Sightline> = (0..side).map(|_| Sightline::default()).collect();
+    let mut from_bottom: Vec<Sightline> = (0..side).map(|_| Sightline::default()).collect();
+    for (row, line) in heights.iter().enumerate() {
+        for (column, &height) in line.iter().enumerate() {
+            see(row, column, from_top[column].look(row, height));
+        }
+    }
+    for (row, line) in heights.iter().enumerate().rev() {
+        for (column, &height) in line.iter().enumerate() {
+            see(
+                row,
+                column,
+                from_bottom[column].look(side - 1 - row, height),
+            );
+        }
+    }
+
+    let visible = visible.iter().flatten().filter(|&&seen| seen).count();
+    let best_score = scores.iter().flatten().copied().max().unwrap_or(0);
+    let input = heights
+        .iter()
+        .map(|row| {
+            row.iter()
+                .filter_map(|&height| char::from_digit(height, 10))
+                .collect::<String>()
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+    return Generated {
+        input,
+        part1: Some(visible.into()),
+        part2: Some(best_score.into()),
+    };
+}
+
+/// Part 1 looking from every tree towards the four edges.
+fn reference_part1(forest: &Forest) -> usize {
+    let (lines, columns) = (forest.number_of_lines(), forest.number_of_columns());
+    let height = |x: usize, y: usize| forest.height(Point2::new(x, y)).unwrap_or_default();
+    let mut visible = 0;
+    for y in 0..lines {
+        for x in 0..columns {
+            let tree = height(x, y);
+            let sights = [
+                (0..x).all(|left| height(left, y) < tree),
+                (x + 1..columns).all(|right| height(right, y) < tree),
+                (0..y).all(|up| height(x, up) < tree),
+                (y + 1..lines).all(|down| height(x, down) < tree),
+            ];
+            if sights.contains(&true) {
+                visible += 1;
+            }
+        }
+    }
+    return visible;
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Forest;
+
+    const YEAR: u16 = 2022;
+
+    const DAY: u8 = 8;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        return parse_into_forest(input);
+    }
+
+    fn part1(&self, forest: &Self::Input) -> Result<Answer, AocError> {
+        return part1(forest).map(Answer::from);
+    }
+
+    fn reference(&self, part: Part, forest: &Self::Input) -> Result<Answer, AocError> {
+        return match part {
+            Part::One => Ok(reference_part1(forest).into()),
+            Part::Two => Err(AocError::NotImplemented { day: 8, part }),
+        };
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        return Some(generate(rng, size));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::input::read_input;
+    use crate::solution::{Part, Puzzle};
+
+    fn input() -> String {
+        return "30373
+25512
+65332
+33549
+35390"
+            .to_string();
+    }
+
+    #[test]
+    fn should_reject_invalid_forests() {
+        assert!(matches!(
+            parse_into_forest("303\n2a5"),
+            Err(AocError::Parse(_))
+        ));
+        assert_eq!(
+            parse_into_forest("303\n25").unwrap_err().to_string(),
+            "parse error at line 2, column 3: expected 3 digits per row, found end of input"
+        );
+        assert!(matches!(parse_into_forest(""), Err(AocError::Parse(_))));
+    }
+
+    #[test]
+    fn should_ignore_the_trailing_newline() {
+        let input = input() + "\n";
+        assert_eq!(Day8.run(Part::One, &input), Ok(21.into()));
+    }
+
+    #[test]
+    fn test_example_part1() {
+        assert_eq!(Day8.run(Part::One, &input()), Ok(21.into()));
+    }
+
+    #[test]
+    fn should_see_over_shorter_trees() {
+        assert_eq!(Day8.run(Part::One, "99999\n03149\n99999"), Ok(14.into()));
+    }
+
+    #[test]
+    fn should_look_back_along_a_line() {
+        let mut sightline = Sightline::default();
+        let sights: Vec<(bool, usize)> = [2, 5, 5, 1, 2]
+            .iter()
+            .enumerate()
+            .map(|(index, &height)| sightline.look(index, height))
+            .collect();
+        assert_eq!(
+            sights,
+            vec![(true, 0), (true, 1), (false, 1), (false, 1), (false, 2)]
+        );
+    }
+
+    /// The best scenic score, looking from every tree towards the four edges.
+    fn naive_best_score(input: &str) -> usize {
+        let heights: Vec<Vec<u32>> = input
+            .lines()
+            .map(|line| line.chars().map(|c| c.to_digit(10).unwrap()).collect())
+            .collect();
+        let side = heights.len();
+        let mut best = 0;
+        for row in 0..side {
+            for column in 0..side {
+                let height = heights[row][column];
+                let sights: [Vec<u32>; 4] = [
+                    (0..row).rev().map(|r| heights[r][column]).collect(),
+                    (row + 1..side).map(|r| heights[r][column]).collect(),
+                    (0..column).rev().map(|c| heights[row][c]).collect(),
+                    (column + 1..side).map(|c| heights[row][c]).collect(),
+                ];
+                let score: usize = sights
+                    .iter()
+                    .map(
+                        |sight| match sight.iter().position(|&tree| tree >= height) {
+                            Some(blocking) => blocking + 1,
+                            None => sight.len(),
+                        },
+                    )
+                    .product();
+                best = best.max(score);
+            }
+        }
+        return best;
+    }
+
+    #[test]
+    fn should_generate_the_best_scenic_score() {
+        for seed in 0..20 {
+            let generated = generate(&mut Rng::new(seed), 1 + seed as usize);
+            assert_eq!(
+                generated.part2,
+                Some(naive_best_score(&generated.input).into()),
+                "seed {}:\n{}",
+                seed,
+                generated.input
+            );
+        }
+    }
+
+    /// No answer was accepted by the website yet, so the real input is only
+    /// checked against the reference.
+    #[test]
+    fn part1_real_input() {
+        let input = read_input(2022, 8).unwrap();
+        let forest = parse_into_forest(&input).unwrap();
+        assert_eq!(
+            Day8.run(Part::One, &input),
+            Ok(reference_part1(&forest).into())
+        );
+    }
+}
@@ -0,0 +1,308 @@
+use std::collections::HashSet;
+
+use nom::{
+    character::complete::{anychar, space1},
+    combinator::map_opt,
+    error::context,
+    sequence::separated_pair,
+};
+
+use crate::{
+    error::AocError,
+    generate::{Generated, Rng},
+    geometry::{Direction4, Point2},
+    parsing::{lines, parse_all, signed, PResult},
+    solution::{Answer, Part, Solution},
+    trace,
+    trace::Level,
+};
+
+/// Position of a knot, `Up` decreasing `y`.
+pub type Coords = Point2<i32>;
+
+/// Where the tail moves to follow the head, `None` when it stays. Fails when
+/// the head got more than one step away in both directions.
+pub fn updateTailPosition(
+    head_position: Coords,
+    tail_position: Coords,
+) -> Result<Option<Coords>, AocError> {
+    let delta = head_position - tail_position;
+    return match head_position.chebyshev(tail_position) {
+        0 | 1 => Ok(None),
+        2 => Ok(Some(tail_position + delta.signum())),
+        _ => Err(AocError::unsupported(format!(
+            "the head {:?} is too far from the tail {:?}",
+            head_position, tail_position
+        ))),
+    };
+}
+fn parse_direction(input: &str) -> PResult<'_, Direction4> {
+    let direction = map_opt(anychar, |direction| match direction {
+        'R' => Some(Direction4::Right),
+        'L' => Some(Direction4::Left),
+        'U' => Some(Direction4::Up),
+        'D' => Some(Direction4::Down),
+        _ => None,
+    });
+    return context("one of R, L, U or D", direction)(input);
+}
+
+/// One move per line, such as `R 4`.
+pub fn parse_input(input: &str) -> Result<Vec<(Direction4, i32)>, AocError> {
+    let moves = parse_all(
+        lines(separated_pair(parse_direction, space1, signed)),
+        input,
+    )?;
+    if let Some(line) = moves.iter().position(|&(_, distance)| distance < 0) {
+        return Err(AocError::validation(format!(
+            "negative distance on line {}",
+            line + 1
+        )));
+    }
+    return Ok(moves);
+}
+
+/// Number of positions the tail of a two knots rope visits.
+pub fn part1(moves: &[(Direction4, i32)]) -> Result<i32, AocError> {
+    let steps = moves
+        .iter()
+        .flat_map(|&(direction, number)| std::iter::repeat_n(direction, number as usize));
+
+    let mut record_of_tail = HashSet::new();
+    let init_loc = Point2::new(0, 4);
+    record_of_tail.insert(init_loc);
+    steps.into_iter().try_fold(
+        (init_loc, init_loc),
+        |(head_position, tail_position), direction| {
+            let new_head = head_position + direction.offset();
+            return match updateTailPosition(new_head, tail_position)? {
+                Some(new_tail) => {
+                    trace!(
+                        Level::Debug,
+                        "head {:?} pulls the tail to {:?}",
+                        new_head,
+                        new_tail
+                    );
+                    record_of_tail.insert(new_tail);
+                    Ok((new_head, new_tail))
+                }
+                None => Ok((new_head, tail_position)),
+            };
+        },
+    )?;
+    return Ok(record_of_tail.len() as i32);
+}
+
+/// Positions visited by the tail of a rope of `knots` knots, followed one
+/// step at a time.
+fn generated_tail_visits(moves: &[(char, u64)], knots: usize) -> usize {
+    let mut rope = vec![(0i64, 0i64); knots];
+    let mut visited = HashSet::from([(0, 0)]);
+    for &(direction, distance) in moves {
+        let (dx, dy) = match direction {
+            'R' => (1, 0),
+            'L' => (-1, 0),
+            'U' => (0, -1),
+            _ => (0, 1),
+        };
+        for _ in 0..distance {
+            rope[0] = (rope[0].0 + dx, rope[0].1 + dy);
+            for knot in 1..knots {
+                let (x, y) = (
+                    rope[knot - 1].0 - rope[knot].0,
+                    rope[knot - 1].1 - rope[knot].1,
+                );
+                if x.abs() > 1 || y.abs() > 1 {
+                    rope[knot] = (rope[knot].0 + x.signum(), rope[knot].1 + y.signum());
+                }
+            }
+            visited.insert(rope[knots - 1]);
+        }
+    }
+    return visited.len();
+}
+
+/// `size` moves of 1 to 9 steps.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let moves: Vec<(char, u64)> = (0..size.max(1))
+        .map(|_| (*rng.pick(&['R', 'L', 'U', 'D']), rng.range(1, 9)))
+        .collect();
+    let input = moves
+        .iter()
+        .map(|(direction, distance)| format!("{} {}", direction, distance))
+        .collect::<Vec<String>>()
+        .join("\n");
+    return Generated {
+        input,
+        part1: Some(generated_tail_visits(&moves, 2).into()),
+        part2: Some(generated_tail_visits(&moves, 10).into()),
+    };
+}
+
+/// Part 1 moving the head one step at a time, the tail stepping towards it
+/// whenever they stop touching.
+fn reference_part1(moves: &[(Direction4, i32)]) -> usize {
+    let (mut head, mut tail) = ((0, 0), (0, 0));
+    let mut visited = HashSet::from([tail]);
+    for &(direction, distance) in moves {
+        let step: Coords = direction.offset();
+        for _ in 0..distance {
+            head = (head.0 + step.x, head.1 + step.y);
+            let (dx, dy): (i32, i32) = (head.0 - tail.0, head.1 - tail.1);
+            if dx.abs() > 1 || dy.abs() > 1 {
+                tail = (tail.0 + dx.signum(), tail.1 + dy.signum());
+                visited.insert(tail);
+            }
+        }
+    }
+    return visited.len();
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<(Direction4, i32)>;
+
+    const YEAR: u16 = 2022;
+
+    const DAY: u8 = 9;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        return parse_input(input);
+    }
+
+    fn part1(&self, moves: &Self::Input) -> Result<Answer, AocError> {
+        return part1(moves).map(Answer::from);
+    }
+
+    fn reference(&self, part: Part, moves: &Self::Input) -> Result<Answer, AocError> {
+        return match part {
+            Part::One => Ok(reference_part1(moves).into()),
+            Part::Two => Err(AocError::NotImplemented { day: 9, part }),
+        };
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        return Some(generate(rng, size));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::answers::known_answer;
+    use crate::input::read_input;
+    use crate::solution::{Part, Puzzle};
+
+    fn p(x: i32, y: i32) -> Coords {
+        return Point2::new(x, y);
+    }
+
+    // fn input() -> String {
+    //     return "R 4
+    //     U "
+    //     .to_string();
+    // }
+    #[test]
+    fn should_not_move_if_the_distance_is_smaller_than_2() {
+        assert_eq!(updateTailPosition(p(2, 2), p(2, 1)), Ok(None));
+        assert_eq!(updateTailPosition(p(2, 2), p(2, 3)), Ok(None));
+        assert_eq!(updateTailPosition(p(2, 2), p(1, 2)), Ok(None));
+        assert_eq!(updateTailPosition(p(2, 2), p(3, 2)), Ok(None));
+        assert_eq!(updateTailPosition(p(2, 2), p(1, 1)), Ok(None));
+        assert_eq!(updateTailPosition(p(2, 2), p(3, 3)), Ok(None));
+        assert_eq!(updateTailPosition(p(2, 2), p(1, 3)), Ok(None));
+        assert_eq!(updateTailPosition(p(2, 2), p(3, 1)), Ok(None));
+    }
+
+    #[test]
+    fn should_move_in_the_right_direction() {
+        // top left
+        assert_eq!(updateTailPosition(p(0, 0), p(2, 2)), Ok(Some(p(1, 1))));
+        assert_eq!(updateTailPosition(p(0, 1), p(2, 2)), Ok(Some(p(1, 1))));
+        assert_eq!(updateTailPosition(p(1, 0), p(2, 2)), Ok(Some(p(1, 1))));
+
+        // middle top
+        assert_eq!(updateTailPosition(p(2, 0), p(2, 2)), Ok(Some(p(2, 1))));
+
+        // top right
+        assert_eq!(updateTailPosition(p(3, 0), p(2, 2)), Ok(Some(p(3, 1))));
+        assert_eq!(updateTailPosition(p(4, 0), p(2, 2)), Ok(Some(p(3, 1))));
+        assert_eq!(updateTailPosition(p(4, 1), p(2, 2)), Ok(Some(p(3, 1))));
+
+        // middle right
+        assert_eq!(updateTailPosition(p(4, 2), p(2, 2)), Ok(Some(p(3, 2))));
+
+        // bottom right
+        assert_eq!(updateTailPosition(p(4, 3), p(2, 2)), Ok(Some(p(3, 3))));
+        assert_eq!(updateTailPosition(p(4, 4), p(2, 2)), Ok(Some(p(3, 3))));
+        assert_eq!(updateTailPosition(p(3, 4), p(2, 2)), Ok(Some(p(3, 3))));
+
+        // bottom middle
+        assert_eq!(updateTailPosition(p(2, 4), p(2, 2)), Ok(Some(p(2, 3))));
+
+        // bottom left
+        assert_eq!(updateTailPosition(p(1, 4), p(2, 2)), Ok(Some(p(1, 3))));
+        assert_eq!(updateTailPosition(p(0, 4), p(2, 2)), Ok(Some(p(1, 3))));
+        assert_eq!(updateTailPosition(p(0, 3), p(2, 2)), Ok(Some(p(1, 3))));
+
+        // middle left
+        assert_eq!(updateTailPosition(p(0, 2), p(2, 2)), Ok(Some(p(1, 2))));
+    }
+
+    #[test]
+    fn should_parse() {
+        let input = "R 4
+U 4
+L 3
+D 1";
+        assert_eq!(
+            parse_input(input),
+            Ok(Vec::from([
+                (Direction4::Right, 4),
+                (Direction4::Up, 4),
+                (Direction4::Left, 3),
+                (Direction4::Down, 1),
+            ]))
+        )
+    }
+
+    #[test]
+    fn should_reject_invalid_moves() {
+        assert!(matches!(parse_input("R 4\nX 2"), Err(AocError::Parse(_))));
+        assert!(matches!(parse_input("R four"), Err(AocError::Parse(_))));
+        assert!(matches!(parse_input("R"), Err(AocError::Parse(_))));
+        assert!(matches!(parse_input("R -1"), Err(AocError::Validation(_))));
+    }
+
+    #[test]
+    fn should_not_handle_a_head_too_far_away() {
+        assert!(matches!(
+            updateTailPosition(p(0, 0), p(3, 0)),
+            Err(AocError::UnsupportedInput(_))
+        ));
+    }
+
+    #[test]
+    fn should_find_out_answer_for_part1() {
+        let input = "R 4
+U 4
+L 3
+D 1
+R 4
+D 1
+L 5
+R 2";
+        assert_eq!(Day9.run(Part::One, input), Ok(13.into()));
+    }
+
+    #[test]
+    fn part1_res() {
+        let input = &read_input(2022, 9).unwrap();
+        assert_eq!(
+            Day9.run(Part::One, input),
+            Ok(known_answer(2022, 9, Part::One))
+        );
+    }
+}
@@ -0,0 +1,26 @@
+//! Advent of Code 2022.
+
+use crate::solution::Puzzle;
+
+pub mod day_1;
+pub mod day_10;
+pub mod day_2;
+pub mod day_3;
+pub mod day_5;
+pub mod day_6;
+pub mod day_7;
+pub mod day_8;
+pub mod day_9;
+
+/// Every day that has a solution, in calendar order.
+pub static DAYS: &[&dyn Puzzle] = &[
+    &day_1::Day1,
+    &day_2::Day2,
+    &day_3::Day3,
+    &day_5::Day5,
+    &day_6::Day6,
+    &day_7::Day7,
+    &day_8::Day8,
+    &day_9::Day9,
+    &day_10::Day10,
+];